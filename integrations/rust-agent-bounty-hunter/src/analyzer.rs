@@ -5,6 +5,9 @@
 //! - Estimating implementation effort
 //! - Identifying dependencies and risks
 
+use crate::audit::{AuditStore, DependencyStatus};
+use crate::corpus::BountyCorpus;
+use crate::tokenizer::{self, REQUIREMENT_INDICATORS};
 use anyhow::Result;
 use std::collections::HashMap;
 
@@ -18,9 +21,14 @@ pub struct BountyAnalysis {
     pub risks: Vec<String>,
     pub dependencies: Vec<String>,
     pub implementation_notes: String,
+    pub fuzzing_plan: Option<FuzzPlan>,
+    /// The minimal set of new `safe-to-deploy` audits a contributor must
+    /// perform before this bounty can close, per `AuditStore::missing_audits`.
+    /// Empty when no `AuditStore` was supplied or nothing is missing.
+    pub required_audits: Vec<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Complexity {
     Trivial,
     Easy,
@@ -39,19 +47,151 @@ impl Complexity {
             Complexity::Expert => "🔴 Expert (16+ hours)",
         }
     }
+
+    /// One step up the Trivial..Expert ladder, capped at Expert.
+    fn bumped(self) -> Complexity {
+        match self {
+            Complexity::Trivial => Complexity::Easy,
+            Complexity::Easy => Complexity::Medium,
+            Complexity::Medium => Complexity::Hard,
+            Complexity::Hard | Complexity::Expert => Complexity::Expert,
+        }
+    }
+}
+
+/// Which kind of fuzz harness suits the bounty: a coverage-guided target
+/// round-tripping a decoder/encoder pair, or a property check asserting no
+/// panic/overflow across an arithmetic operation's input domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum HarnessStyle {
+    RoundTrip,
+    PropertyCheck,
+}
+
+impl HarnessStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HarnessStyle::RoundTrip => {
+                "coverage-guided fuzz target taking &[u8]: decode -> encode -> decode, assert equality"
+            }
+            HarnessStyle::PropertyCheck => {
+                "property check over the input domain: assert no panic/overflow"
+            }
+        }
+    }
+}
+
+/// A recommended fuzzing setup for a bounty whose requirements touch a
+/// decoder, serializer, or arithmetic code path - surfaced so a reviewer can
+/// require a harness before merging.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FuzzPlan {
+    pub harness_style: HarnessStyle,
+    pub invariants: Vec<String>,
+    pub notes: String,
+}
+
+const PARSER_SIGNALS: &[&str] = &[
+    "parse", "parsing", "parser", "decode", "decoder", "encode", "encoder", "serialize",
+    "deserialize", "serde", "codec", "protocol", "wire", "format",
+];
+
+const ARITHMETIC_SIGNALS: &[&str] = &[
+    "arithmetic", "overflow", "underflow", "checked", "wrapping", "saturating", "calculate",
+    "numeric", "math",
+];
+
+/// Inspects tokenized title+body text for parser/codec/serde or
+/// arithmetic/overflow signals and, if found, recommends a fuzzing setup.
+/// Parser/codec signals win when both are present, since a round-trip
+/// harness for the decoder will usually exercise the arithmetic inside it
+/// too, while the reverse isn't true.
+fn detect_fuzz_plan(tokens: &[String]) -> Option<FuzzPlan> {
+    let has_parser_signal = tokens.iter().any(|t| PARSER_SIGNALS.contains(&t.as_str()));
+    let has_arithmetic_signal = tokens
+        .iter()
+        .any(|t| ARITHMETIC_SIGNALS.contains(&t.as_str()));
+
+    if has_parser_signal {
+        return Some(FuzzPlan {
+            harness_style: HarnessStyle::RoundTrip,
+            invariants: vec![
+                "decode(encode(x)) == x for every generated input".to_string(),
+                "decode never panics on malformed or truncated input".to_string(),
+                "encode output always round-trips through decode without data loss".to_string(),
+            ],
+            notes: "Ship a `fuzz/` target (cargo-fuzz) plus a seed corpus of real and \
+                    deliberately malformed inputs checked into the repo."
+                .to_string(),
+        });
+    }
+
+    if has_arithmetic_signal {
+        return Some(FuzzPlan {
+            harness_style: HarnessStyle::PropertyCheck,
+            invariants: vec![
+                "the operation never panics (no unchecked overflow, underflow, or divide-by-zero) \
+                 across the input domain"
+                    .to_string(),
+                "results stay within documented bounds for every generated input".to_string(),
+            ],
+            notes: "Ship a property-based fuzz target (cargo-fuzz) exercising the full numeric \
+                    input range, plus a reproducible corpus of any failing cases it finds."
+                .to_string(),
+        });
+    }
+
+    None
 }
 
 pub fn analyze_bounty(
     number: u64,
     title: &str,
     body: &str,
+    corpus: Option<&BountyCorpus>,
+    audits: Option<&AuditStore>,
 ) -> BountyAnalysis {
     let requirements = extract_requirements(body);
-    let complexity = assess_complexity(&requirements, title);
-    let effort = estimate_effort(&complexity);
-    let risks = identify_risks(body, &requirements);
+
+    // If the corpus has a confident nearest-neighbor match, trust its
+    // estimate over the fixed keyword weights below - it's grounded in how
+    // long similar bounties actually took, not a guess at what "async" or
+    // "database" implies about effort.
+    let tokens = tokenizer::tokenize(&format!("{} {}", title, body));
+    let corpus_estimate = corpus.and_then(|c| c.estimate(&tokens));
+
+    let mut complexity = corpus_estimate
+        .as_ref()
+        .map(|e| e.complexity)
+        .unwrap_or_else(|| assess_complexity(&requirements, title));
+
+    let (risks, complexity_bumps, forces_expert) = identify_risks(body, &requirements, audits);
+    if forces_expert {
+        complexity = Complexity::Expert;
+    } else {
+        for _ in 0..complexity_bumps {
+            complexity = complexity.bumped();
+        }
+    }
+
+    // A dependency-audit bump overrides the corpus's historical estimate -
+    // "similar bounties took 4 hours" doesn't account for a newly-required
+    // safe-to-deploy review that wasn't part of any of those bounties.
+    let effort = if forces_expert || complexity_bumps > 0 {
+        estimate_effort(&complexity)
+    } else {
+        corpus_estimate
+            .as_ref()
+            .map(|e| e.effort_label())
+            .unwrap_or_else(|| estimate_effort(&complexity))
+    };
+
     let dependencies = find_dependencies(body);
     let notes = generate_implementation_notes(title, body, &complexity);
+    let fuzzing_plan = detect_fuzz_plan(&tokens);
+    let required_audits = audits
+        .map(|store| store.missing_audits(&crate::audit::detect_crate_mentions(body)))
+        .unwrap_or_default();
 
     BountyAnalysis {
         number,
@@ -62,30 +202,40 @@ pub fn analyze_bounty(
         risks,
         dependencies,
         implementation_notes: notes,
+        fuzzing_plan,
+        required_audits,
     }
 }
 
 fn extract_requirements(body: &str) -> Vec<String> {
     let mut requirements = Vec::new();
-    let lines: Vec<&str> = body.lines().collect();
-    
-    for line in lines {
-        let line = line.trim().to_lowercase();
-        
-        // Look for requirement indicators
-        if line.starts_with("- ") || line.starts_with("* ") || line.starts_with("1.") {
-            let req = line.trim_start_matches("- ")
-                .trim_start_matches("* ")
-                .trim_start_matches("1. ")
-                .to_string();
-            if !req.is_empty() && req.len() > 3 {
-                requirements.push(req);
-            }
+
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        // Bullet/numbered lines are almost always a requirement regardless
+        // of wording; everything else only counts if it actually contains a
+        // requirement-indicator word once tokenized. Tokenizing first (and
+        // emitting the segmented tokens, not the raw line) is what lets this
+        // fire on CJK prose, which has no "- " markers or "should"/"must" to
+        // find by raw substring search.
+        let is_bullet =
+            trimmed.starts_with("- ") || trimmed.starts_with("* ") || trimmed.starts_with("1.");
+
+        let tokens = tokenizer::tokenize(trimmed);
+        if tokens.is_empty() {
+            continue;
         }
-        
-        // Look for "should", "must", "need to" patterns
-        if line.contains("should") || line.contains("must") || line.contains("need to") {
-            requirements.push(line.to_string());
+
+        let has_indicator = tokens
+            .iter()
+            .any(|t| REQUIREMENT_INDICATORS.contains(&t.as_str()));
+
+        if is_bullet || has_indicator {
+            requirements.push(tokens.join(" "));
         }
     }
 
@@ -161,35 +311,85 @@ fn estimate_effort(complexity: &Complexity) -> String {
     }
 }
 
-fn identify_risks(body: &str, requirements: &[String]) -> Vec<String> {
+/// The midpoint of `estimate_effort`'s band for `complexity`, in hours. Used
+/// to record a completed bounty into the `BountyCorpus` with a concrete
+/// number rather than the free-text effort range shown to a user.
+pub fn effort_hours_midpoint(complexity: &Complexity) -> f64 {
+    match complexity {
+        Complexity::Trivial => 0.5,
+        Complexity::Easy => 2.5,
+        Complexity::Medium => 6.0,
+        Complexity::Hard => 12.0,
+        Complexity::Expert => 20.0,
+    }
+}
+
+/// Returns the plain-English risk list, how many `Complexity` levels the
+/// audit findings should bump the bounty by, and whether a known-vulnerable
+/// dependency means that bump should go straight to `Expert` instead.
+fn identify_risks(
+    body: &str,
+    requirements: &[String],
+    audits: Option<&AuditStore>,
+) -> (Vec<String>, u32, bool) {
     let mut risks = Vec::new();
-    
+
     // Check for breaking change indicators
     if body.to_lowercase().contains("breaking") {
         risks.push("Breaking change - requires migration guide".to_string());
     }
-    
+
     // Check for production impact
     if body.to_lowercase().contains("production") || body.to_lowercase().contains("live") {
         risks.push("Production impact - requires thorough testing".to_string());
     }
-    
+
     // Check for security implications
     if body.to_lowercase().contains("security") || body.to_lowercase().contains("vulnerability") {
         risks.push("Security-sensitive - requires security review".to_string());
     }
-    
+
     // Check for external dependencies
     if body.to_lowercase().contains("api") || body.to_lowercase().contains("external") {
         risks.push("External dependency - may break if API changes".to_string());
     }
-    
+
     // Complexity risks
     if requirements.len() > 10 {
         risks.push("Many requirements - risk of scope creep".to_string());
     }
-    
-    risks
+
+    let mut complexity_bumps = 0u32;
+    let mut forces_expert = false;
+    if let Some(store) = audits {
+        for (name, version) in crate::audit::detect_crate_mentions(body) {
+            match store.classify(&name, version) {
+                DependencyStatus::Unaudited => {
+                    risks.push(format!(
+                        "Introduces unaudited dependency `{} {}` - requires safe-to-deploy review",
+                        name, version
+                    ));
+                    complexity_bumps += 1;
+                }
+                DependencyStatus::Exempted(_) => {
+                    risks.push(format!(
+                        "Dependency `{} {}` is only exempted, not audited - consider a real safe-to-deploy review",
+                        name, version
+                    ));
+                }
+                DependencyStatus::KnownVulnerable(advisory) => {
+                    risks.push(format!(
+                        "Dependency `{} {}` has a known vulnerability ({}) - must be patched or replaced",
+                        name, version, advisory
+                    ));
+                    forces_expert = true;
+                }
+                DependencyStatus::Audited(_) => {}
+            }
+        }
+    }
+
+    (risks, complexity_bumps, forces_expert)
 }
 
 fn find_dependencies(body: &str) -> Vec<String> {
@@ -0,0 +1,312 @@
+//! Dependency Audit - cargo-vet-style supply-chain risk scoring
+//!
+//! `find_dependencies` only ever emitted friendly strings ("tokio async
+//! runtime") with no way to say whether a crate is actually trustworthy.
+//! This module maintains an `AuditStore` modeled on cargo-vet: full audits
+//! (crate+version certified for a criterion by a named auditor), delta
+//! audits (crate is certified for X->Y if the base X is already audited and
+//! the delta was reviewed), a list of trusted publishers, and exemptions
+//! (claimed without a real review). `identify_risks` resolves every crate a
+//! bounty's body names against the store and classifies it as audited,
+//! exempted, unaudited, or known-vulnerable.
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Crate names this subsystem looks for in a bounty's body. Detecting crate
+/// mentions in free-form issue text is inherently heuristic, so this stays
+/// an explicit allow-list - the crates this repo's bounties actually deal
+/// with - rather than flagging every lowercase word next to a version.
+const KNOWN_CRATES: &[&str] = &[
+    "tokio",
+    "serde",
+    "serde_json",
+    "reqwest",
+    "anyhow",
+    "thiserror",
+    "async-trait",
+    "sqlx",
+    "diesel",
+    "rusqlite",
+    "hyper",
+    "axum",
+    "actix-web",
+    "rocket",
+    "openssl",
+    "rustls",
+    "native-tls",
+    "ring",
+    "regex",
+    "rand",
+    "chrono",
+    "uuid",
+    "log",
+    "tracing",
+    "clap",
+    "rayon",
+    "crossbeam",
+    "hmac",
+    "sha2",
+    "git2",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditCriterion {
+    SafeToRun,
+    SafeToDeploy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    pub fn parse(s: &str) -> Option<Version> {
+        let mut parts = s.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next().unwrap_or("0").parse().unwrap_or(0);
+        Some(Version { major, minor, patch })
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FullAudit {
+    pub crate_name: String,
+    pub version: Version,
+    pub criteria: Vec<AuditCriterion>,
+    pub who: String,
+}
+
+/// Certifies that the delta from an already-audited `from` version to `to`
+/// was reviewed, without re-auditing `to` from scratch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeltaAudit {
+    pub crate_name: String,
+    pub from: Version,
+    pub to: Version,
+    pub criteria: Vec<AuditCriterion>,
+    pub who: String,
+}
+
+/// A criterion claimed for a crate+version without a real audit, e.g. to
+/// unblock a release while the real review is still pending.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Exemption {
+    pub crate_name: String,
+    pub version: Version,
+    pub criteria: Vec<AuditCriterion>,
+    pub reason: String,
+}
+
+/// A crates.io publisher trusted to grant `safe-to-run` on their crates
+/// without a dedicated per-version audit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustedPublisher {
+    pub crate_name: String,
+    pub publisher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownVulnerability {
+    pub crate_name: String,
+    pub version: Version,
+    pub advisory: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditStore {
+    #[serde(default)]
+    pub full_audits: Vec<FullAudit>,
+    #[serde(default)]
+    pub delta_audits: Vec<DeltaAudit>,
+    #[serde(default)]
+    pub exemptions: Vec<Exemption>,
+    #[serde(default)]
+    pub trusted_publishers: Vec<TrustedPublisher>,
+    #[serde(default)]
+    pub known_vulnerabilities: Vec<KnownVulnerability>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DependencyStatus {
+    Audited(Vec<AuditCriterion>),
+    Exempted(Vec<AuditCriterion>),
+    Unaudited,
+    KnownVulnerable(String),
+}
+
+impl AuditStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let raw = std::fs::read_to_string(path.as_ref())
+            .with_context(|| format!("Failed to read audit store {}", path.as_ref().display()))?;
+        toml::from_str(&raw).context("Failed to parse audit store TOML")
+    }
+
+    /// Loads `path` if it exists, or starts an empty store otherwise - a
+    /// fresh install hasn't recorded any audits yet.
+    pub fn load_or_empty(path: impl AsRef<Path>) -> Result<Self> {
+        match std::fs::metadata(path.as_ref()) {
+            Ok(_) => Self::load(path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e)
+                .with_context(|| format!("Failed to check audit store {}", path.as_ref().display())),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let toml_str = toml::to_string_pretty(self).context("Failed to serialize audit store")?;
+        if let Some(parent) = path.as_ref().parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent).context("Failed to create audit store directory")?;
+            }
+        }
+        std::fs::write(path.as_ref(), toml_str)
+            .with_context(|| format!("Failed to write audit store {}", path.as_ref().display()))
+    }
+
+    /// Whether `version` is certified for `criterion`, either by a direct
+    /// full audit or transitively through a chain of delta audits rooted at
+    /// a fully-audited base version - cargo-vet's "X is safe because the
+    /// delta from the already-audited Y to X was certified" model.
+    fn audited_for(&self, crate_name: &str, version: Version, criterion: AuditCriterion) -> bool {
+        let mut known: HashSet<Version> = self
+            .full_audits
+            .iter()
+            .filter(|a| a.crate_name == crate_name && a.criteria.contains(&criterion))
+            .map(|a| a.version)
+            .collect();
+
+        loop {
+            if known.contains(&version) {
+                return true;
+            }
+            let mut added = false;
+            for delta in &self.delta_audits {
+                if delta.crate_name == crate_name
+                    && delta.criteria.contains(&criterion)
+                    && known.contains(&delta.from)
+                    && known.insert(delta.to)
+                {
+                    added = true;
+                }
+            }
+            if !added {
+                return known.contains(&version);
+            }
+        }
+    }
+
+    fn exempted_criteria(&self, crate_name: &str, version: Version) -> Vec<AuditCriterion> {
+        self.exemptions
+            .iter()
+            .filter(|e| e.crate_name == crate_name && e.version == version)
+            .flat_map(|e| e.criteria.clone())
+            .collect()
+    }
+
+    fn is_trusted_publisher(&self, crate_name: &str) -> bool {
+        self.trusted_publishers
+            .iter()
+            .any(|t| t.crate_name == crate_name)
+    }
+
+    /// Classifies `crate_name`@`version` against this store. A crate from a
+    /// trusted publisher is treated as carrying `safe-to-run` without a
+    /// dedicated audit entry, matching cargo-vet's trusted-publisher
+    /// shortcut. Known vulnerabilities always win, even over an existing
+    /// audit, since an audit predating the advisory can't have seen it.
+    pub fn classify(&self, crate_name: &str, version: Version) -> DependencyStatus {
+        if let Some(vuln) = self
+            .known_vulnerabilities
+            .iter()
+            .find(|v| v.crate_name == crate_name && v.version == version)
+        {
+            return DependencyStatus::KnownVulnerable(vuln.advisory.clone());
+        }
+
+        let criteria: Vec<AuditCriterion> = [AuditCriterion::SafeToRun, AuditCriterion::SafeToDeploy]
+            .into_iter()
+            .filter(|criterion| self.audited_for(crate_name, version, *criterion))
+            .collect();
+        if !criteria.is_empty() {
+            return DependencyStatus::Audited(criteria);
+        }
+
+        if self.is_trusted_publisher(crate_name) {
+            return DependencyStatus::Audited(vec![AuditCriterion::SafeToRun]);
+        }
+
+        let exempted = self.exempted_criteria(crate_name, version);
+        if !exempted.is_empty() {
+            return DependencyStatus::Exempted(exempted);
+        }
+
+        DependencyStatus::Unaudited
+    }
+
+    /// The minimal set of new audits a contributor must perform to close a
+    /// bounty that would add these dependencies: one entry per crate/version
+    /// the store can't already certify as `safe-to-deploy`.
+    pub fn missing_audits(&self, mentions: &[(String, Version)]) -> Vec<String> {
+        mentions
+            .iter()
+            .filter_map(|(name, version)| match self.classify(name, *version) {
+                DependencyStatus::Audited(criteria)
+                    if criteria.contains(&AuditCriterion::SafeToDeploy) =>
+                {
+                    None
+                }
+                DependencyStatus::KnownVulnerable(advisory) => Some(format!(
+                    "{} {} - known-vulnerable ({}), needs a fixed version audited safe-to-deploy",
+                    name, version, advisory
+                )),
+                _ => Some(format!("{} {} needs a safe-to-deploy audit", name, version)),
+            })
+            .collect()
+    }
+}
+
+/// Finds `crate_name = "x.y.z"` / `crate_name v1.2.3` / `crate_name@1.2.3`
+/// style mentions of any crate in `KNOWN_CRATES` within free-form issue
+/// text, deduplicated and sorted for stable output.
+pub fn detect_crate_mentions(body: &str) -> Vec<(String, Version)> {
+    static MENTION_RE: OnceLock<Regex> = OnceLock::new();
+    let re = MENTION_RE.get_or_init(|| {
+        Regex::new(r#"(?i)\b([a-z][a-z0-9_-]{1,30})\b[\s="@:]{0,4}v?(\d+\.\d+(?:\.\d+)?)"#)
+            .expect("valid regex")
+    });
+
+    let mut mentions: Vec<(String, Version)> = re
+        .captures_iter(body)
+        .filter_map(|cap| {
+            let name = cap[1].to_lowercase();
+            if !KNOWN_CRATES.contains(&name.as_str()) {
+                return None;
+            }
+            Version::parse(&cap[2]).map(|version| (name, version))
+        })
+        .collect();
+
+    mentions.sort();
+    mentions.dedup();
+    mentions
+}
@@ -0,0 +1,306 @@
+//! Bounty Corpus - TF-IDF similarity model over previously analyzed bounties
+//!
+//! `assess_complexity`'s fixed keyword weights don't adapt to a given
+//! project's vocabulary. This module builds an inverted index over
+//! already-analyzed bounties (each recorded with its token set and a
+//! known/confirmed `Complexity` and effort in hours), represents every
+//! bounty as a sparse TF-IDF vector, and estimates a new bounty's complexity
+//! and effort via cosine-similarity k-NN: take the `k` nearest historical
+//! bounties, weight their recorded effort by similarity, and average. The
+//! corpus can be empty (a fresh install has recorded nothing yet) or simply
+//! have no close neighbor for an unfamiliar bounty; either case is surfaced
+//! as `None` so `analyzer` can fall back to the keyword heuristic.
+
+use crate::analyzer::Complexity;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Current corpus file format; bump when `CorpusEntry`'s shape changes so a
+/// stale corpus fails loudly instead of loading silently-wrong vectors.
+pub const CORPUS_VERSION: u32 = 1;
+
+/// How many nearest neighbors vote on the estimate.
+const NEIGHBORS_K: usize = 3;
+
+/// Neighbors below this cosine similarity are treated as unrelated rather
+/// than forced to contribute to the estimate.
+const SIMILARITY_THRESHOLD: f64 = 0.15;
+
+const COMPLEXITY_ORDER: [Complexity; 5] = [
+    Complexity::Trivial,
+    Complexity::Easy,
+    Complexity::Medium,
+    Complexity::Hard,
+    Complexity::Expert,
+];
+
+fn complexity_rank(complexity: Complexity) -> usize {
+    match complexity {
+        Complexity::Trivial => 0,
+        Complexity::Easy => 1,
+        Complexity::Medium => 2,
+        Complexity::Hard => 3,
+        Complexity::Expert => 4,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CorpusEntry {
+    term_counts: HashMap<String, u64>,
+    doc_len: u64,
+    complexity: Complexity,
+    effort_hours: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CorpusFile {
+    version: u32,
+    entries: Vec<CorpusEntry>,
+}
+
+/// The nearest-neighbor result of `BountyCorpus::estimate`.
+#[derive(Debug, Clone)]
+pub struct CorpusEstimate {
+    pub complexity: Complexity,
+    pub estimated_hours: f64,
+    pub neighbors_considered: usize,
+}
+
+impl CorpusEstimate {
+    pub fn effort_label(&self) -> String {
+        format!(
+            "~{:.1} hours (estimated from {} similar bount{})",
+            self.estimated_hours,
+            self.neighbors_considered,
+            if self.neighbors_considered == 1 { "y" } else { "ies" }
+        )
+    }
+}
+
+/// A growing collection of analyzed bounties, indexed for TF-IDF similarity
+/// search. `inverted_index` maps each term to the entries it appears in, so
+/// document frequency is a lookup rather than a scan over every entry.
+pub struct BountyCorpus {
+    entries: Vec<CorpusEntry>,
+    inverted_index: HashMap<String, HashSet<usize>>,
+}
+
+impl BountyCorpus {
+    pub fn new() -> Self {
+        BountyCorpus {
+            entries: Vec::new(),
+            inverted_index: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Records a bounty whose complexity and effort are already known (e.g.
+    /// from a completed submission), so future estimates can draw on it.
+    pub fn add(&mut self, tokens: &[String], complexity: Complexity, effort_hours: f64) {
+        let mut term_counts = HashMap::new();
+        for token in tokens {
+            *term_counts.entry(token.clone()).or_insert(0u64) += 1;
+        }
+
+        let doc_id = self.entries.len();
+        for term in term_counts.keys() {
+            self.inverted_index
+                .entry(term.clone())
+                .or_default()
+                .insert(doc_id);
+        }
+
+        self.entries.push(CorpusEntry {
+            term_counts,
+            doc_len: tokens.len() as u64,
+            complexity,
+            effort_hours,
+        });
+    }
+
+    /// `ln(N / df(term))`, where `df` is how many entries contain the term.
+    /// A term present in every entry carries no discriminating weight (0.0);
+    /// a term absent from the corpus entirely carries none either, since
+    /// there's nothing to compare it against.
+    fn idf(&self, term: &str) -> f64 {
+        let n = self.entries.len() as f64;
+        if n == 0.0 {
+            return 0.0;
+        }
+        let df = self
+            .inverted_index
+            .get(term)
+            .map(HashSet::len)
+            .unwrap_or(0) as f64;
+        if df == 0.0 {
+            return 0.0;
+        }
+        (n / df).ln()
+    }
+
+    fn tfidf_vector(&self, term_counts: &HashMap<String, u64>, doc_len: u64) -> HashMap<String, f64> {
+        if doc_len == 0 {
+            return HashMap::new();
+        }
+        term_counts
+            .iter()
+            .map(|(term, count)| {
+                let tf = *count as f64 / doc_len as f64;
+                (term.clone(), tf * self.idf(term))
+            })
+            .collect()
+    }
+
+    /// Estimates complexity and effort for a new bounty's tokens by
+    /// weighting the `k` nearest historical bounties (by cosine similarity
+    /// over their TF-IDF vectors) by how similar they are. Returns `None`
+    /// when the corpus is empty or nothing clears `SIMILARITY_THRESHOLD`,
+    /// so callers know to fall back to the keyword heuristic instead of
+    /// trusting a guess built on unrelated history.
+    pub fn estimate(&self, tokens: &[String]) -> Option<CorpusEstimate> {
+        if self.entries.is_empty() || tokens.is_empty() {
+            return None;
+        }
+
+        let mut query_counts = HashMap::new();
+        for token in tokens {
+            *query_counts.entry(token.clone()).or_insert(0u64) += 1;
+        }
+        let query_vector = self.tfidf_vector(&query_counts, tokens.len() as u64);
+
+        let mut scored: Vec<(f64, &CorpusEntry)> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let entry_vector = self.tfidf_vector(&entry.term_counts, entry.doc_len);
+                (cosine_similarity(&query_vector, &entry_vector), entry)
+            })
+            .filter(|(similarity, _)| *similarity >= SIMILARITY_THRESHOLD)
+            .collect();
+
+        if scored.is_empty() {
+            return None;
+        }
+
+        scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(NEIGHBORS_K);
+
+        let total_weight: f64 = scored.iter().map(|(similarity, _)| similarity).sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        let estimated_hours = scored
+            .iter()
+            .map(|(similarity, entry)| similarity * entry.effort_hours)
+            .sum::<f64>()
+            / total_weight;
+
+        let mut complexity_weights = [0.0f64; 5];
+        for (similarity, entry) in &scored {
+            complexity_weights[complexity_rank(entry.complexity)] += similarity;
+        }
+        let (best_idx, _) = complexity_weights
+            .iter()
+            .enumerate()
+            .fold((0, f64::NEG_INFINITY), |(best_idx, best), (idx, weight)| {
+                if *weight > best {
+                    (idx, *weight)
+                } else {
+                    (best_idx, best)
+                }
+            });
+
+        Some(CorpusEstimate {
+            complexity: COMPLEXITY_ORDER[best_idx],
+            estimated_hours,
+            neighbors_considered: scored.len(),
+        })
+    }
+
+    /// Writes every recorded entry to `path`, overwriting it.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let file = CorpusFile {
+            version: CORPUS_VERSION,
+            entries: self.entries.clone(),
+        };
+        if let Some(parent) = path.as_ref().parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent).context("Failed to create corpus directory")?;
+            }
+        }
+        let json = serde_json::to_string_pretty(&file).context("Failed to serialize corpus file")?;
+        std::fs::write(path.as_ref(), json)
+            .with_context(|| format!("Failed to write corpus file {}", path.as_ref().display()))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let raw = std::fs::read_to_string(path.as_ref())
+            .with_context(|| format!("Failed to read corpus file {}", path.as_ref().display()))?;
+        let file: CorpusFile = serde_json::from_str(&raw).context("Failed to parse corpus file")?;
+        if file.version != CORPUS_VERSION {
+            bail!(
+                "Corpus file {} is version {}, expected {}",
+                path.as_ref().display(),
+                file.version,
+                CORPUS_VERSION
+            );
+        }
+
+        let mut corpus = BountyCorpus::new();
+        for entry in file.entries {
+            let doc_id = corpus.entries.len();
+            for term in entry.term_counts.keys() {
+                corpus
+                    .inverted_index
+                    .entry(term.clone())
+                    .or_default()
+                    .insert(doc_id);
+            }
+            corpus.entries.push(entry);
+        }
+        Ok(corpus)
+    }
+
+    /// Loads `path` if it exists, or starts an empty corpus otherwise - a
+    /// fresh install hasn't recorded any bounties yet.
+    pub fn load_or_empty(path: impl AsRef<Path>) -> Result<Self> {
+        match std::fs::metadata(path.as_ref()) {
+            Ok(_) => Self::load(path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e).with_context(|| {
+                format!("Failed to check corpus file {}", path.as_ref().display())
+            }),
+        }
+    }
+}
+
+impl Default for BountyCorpus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn cosine_similarity(a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> f64 {
+    let (smaller, larger) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let dot: f64 = smaller
+        .iter()
+        .filter_map(|(term, weight)| larger.get(term).map(|other| weight * other))
+        .sum();
+    let norm_a = a.values().map(|w| w * w).sum::<f64>().sqrt();
+    let norm_b = b.values().map(|w| w * w).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
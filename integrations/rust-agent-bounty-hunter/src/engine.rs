@@ -0,0 +1,848 @@
+//! Remote Git Engine - Abstracts GitHub, Gitea, and GitLab behind one trait
+//!
+//! This module handles:
+//! - A `RemoteGitEngine` trait the high-level claim/submit flows program against
+//! - Concrete clients for GitHub, Gitea, and GitLab, each with their own base
+//!   URL, auth header format, and JSON field mappings
+
+use crate::http;
+use crate::scanner::BountyLead;
+use crate::submitter::SubmitResult;
+use crate::transport::{HttpRequest, ReqwestTransport, Transport};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Forge-agnostic operations the claim/submit pipeline needs from a remote. Each
+/// concrete implementation translates these into that forge's REST dialect
+/// (GitHub pulls/issues, Gitea's near-identical API, or GitLab's merge_requests
+/// and internal IDs).
+#[async_trait]
+pub trait RemoteGitEngine: Send + Sync {
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: &str,
+        head: &str,
+        base: &str,
+    ) -> Result<SubmitResult>;
+
+    async fn post_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        body: &str,
+    ) -> Result<SubmitResult>;
+
+    async fn update_issue_labels_state(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        labels: Option<Vec<&str>>,
+        state: Option<&str>,
+    ) -> Result<SubmitResult>;
+
+    async fn submit_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        event: &str,
+        body: &str,
+    ) -> Result<SubmitResult>;
+
+    async fn list_open_issues(&self, owner: &str, repo: &str) -> Result<Vec<BountyLead>>;
+}
+
+// ---------------------------------------------------------------------------
+// GitHub
+// ---------------------------------------------------------------------------
+
+pub struct GitHubClient {
+    transport: Arc<dyn Transport>,
+    token: String,
+    base_url: String,
+}
+
+impl GitHubClient {
+    pub fn new(token: impl Into<String>) -> Self {
+        GitHubClient {
+            transport: Arc::new(ReqwestTransport::new(http::shared_client())),
+            token: token.into(),
+            base_url: "https://api.github.com".to_string(),
+        }
+    }
+
+    /// Builds a client against an arbitrary `Transport`, e.g. a
+    /// `ReplayTransport` serving fixtures in tests.
+    pub fn with_transport(transport: Arc<dyn Transport>, token: impl Into<String>) -> Self {
+        GitHubClient {
+            transport,
+            token: token.into(),
+            base_url: "https://api.github.com".to_string(),
+        }
+    }
+
+    fn auth_header(&self) -> String {
+        format!("token {}", self.token)
+    }
+}
+
+#[async_trait]
+impl RemoteGitEngine for GitHubClient {
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: &str,
+        head: &str,
+        base: &str,
+    ) -> Result<SubmitResult> {
+        let url = format!("{}/repos/{}/{}/pulls", self.base_url, owner, repo);
+        let payload = json!({ "title": title, "body": body, "head": head, "base": base });
+
+        let request = HttpRequest::new("POST", url)
+            .header("Authorization", self.auth_header())
+            .header("Accept", "application/vnd.github.v3+json")
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to create PR")?;
+
+        if response.is_success() {
+            let pr: serde_json::Value = response.json().context("Failed to parse PR response")?;
+            let pr_url = pr["html_url"].as_str().unwrap_or("").to_string();
+            Ok(SubmitResult {
+                success: true,
+                action: "PR Created".to_string(),
+                url: Some(pr_url.clone()),
+                message: format!("PR created successfully: {}", pr_url),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "PR Creation Failed".to_string(),
+                url: None,
+                message: format!("Failed to create PR: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn post_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        body: &str,
+    ) -> Result<SubmitResult> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/comments",
+            self.base_url, owner, repo, issue_number
+        );
+        let payload = json!({ "body": body });
+
+        let request = HttpRequest::new("POST", url)
+            .header("Authorization", self.auth_header())
+            .header("Accept", "application/vnd.github.v3+json")
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to post comment")?;
+
+        if response.is_success() {
+            let comment: serde_json::Value =
+                response.json().context("Failed to parse comment response")?;
+            Ok(SubmitResult {
+                success: true,
+                action: "Comment Posted".to_string(),
+                url: comment["html_url"].as_str().map(String::from),
+                message: "Comment posted successfully".to_string(),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "Comment Failed".to_string(),
+                url: None,
+                message: format!("Failed to post comment: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn update_issue_labels_state(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        labels: Option<Vec<&str>>,
+        state: Option<&str>,
+    ) -> Result<SubmitResult> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}",
+            self.base_url, owner, repo, issue_number
+        );
+        let mut payload = json!({});
+        if let Some(labels) = labels {
+            payload["labels"] = json!(labels);
+        }
+        if let Some(state) = state {
+            payload["state"] = json!(state);
+        }
+
+        let request = HttpRequest::new("PATCH", url)
+            .header("Authorization", self.auth_header())
+            .header("Accept", "application/vnd.github.v3+json")
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to update issue")?;
+
+        if response.is_success() {
+            Ok(SubmitResult {
+                success: true,
+                action: "Issue Updated".to_string(),
+                url: None,
+                message: "Issue updated successfully".to_string(),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "Update Failed".to_string(),
+                url: None,
+                message: format!("Failed to update issue: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn submit_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        event: &str,
+        body: &str,
+    ) -> Result<SubmitResult> {
+        let url = format!(
+            "{}/repos/{}/{}/pulls/{}/reviews",
+            self.base_url, owner, repo, pr_number
+        );
+        let payload = json!({ "event": event, "body": body });
+
+        let request = HttpRequest::new("POST", url)
+            .header("Authorization", self.auth_header())
+            .header("Accept", "application/vnd.github.v3+json")
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to submit review")?;
+
+        if response.is_success() {
+            Ok(SubmitResult {
+                success: true,
+                action: "Review Submitted".to_string(),
+                url: None,
+                message: format!("Review submitted: {}", event),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "Review Failed".to_string(),
+                url: None,
+                message: format!("Failed to submit review: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn list_open_issues(&self, owner: &str, repo: &str) -> Result<Vec<BountyLead>> {
+        let url = format!(
+            "{}/repos/{}/{}/issues?state=open&per_page=100",
+            self.base_url, owner, repo
+        );
+        let request = HttpRequest::new("GET", url)
+            .header("Authorization", self.auth_header())
+            .header("Accept", "application/vnd.github.v3+json");
+        let response: serde_json::Value = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to fetch issues")?
+            .json()
+            .context("Failed to parse issues")?;
+
+        let issues = response.as_array().context("Issues should be an array")?;
+        Ok(issues
+            .iter()
+            .filter(|issue| issue.get("pull_request").is_none())
+            .map(|issue| BountyLead {
+                number: issue["number"].as_u64().unwrap_or(0),
+                title: issue["title"].as_str().unwrap_or("").to_string(),
+                body: issue["body"].as_str().unwrap_or("").to_string(),
+                labels: issue["labels"]
+                    .as_array()
+                    .map(|arr| {
+                        arr.iter()
+                            .filter_map(|l| l["name"].as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                reward_estimate: "Unspecified".to_string(),
+                difficulty: "Normal".to_string(),
+                url: issue["html_url"].as_str().unwrap_or("").to_string(),
+                repository: format!("{}/{}", owner, repo),
+                reactions: issue["reactions"]["total_count"].as_u64().unwrap_or(0),
+                comments: issue["comments"].as_u64().unwrap_or(0),
+                created_at: issue["created_at"].as_str().unwrap_or("").to_string(),
+            })
+            .collect())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Gitea
+// ---------------------------------------------------------------------------
+
+/// Gitea's API is a near-mirror of GitHub's for the endpoints this tool uses,
+/// differing mainly in base URL and the `/api/v1` path prefix; it takes the
+/// same `token` auth header scheme.
+pub struct GiteaClient {
+    transport: Arc<dyn Transport>,
+    token: String,
+    base_url: String,
+}
+
+impl GiteaClient {
+    pub fn new(base_url: impl Into<String>, token: impl Into<String>) -> Self {
+        GiteaClient {
+            transport: Arc::new(ReqwestTransport::new(http::shared_client())),
+            token: token.into(),
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Builds a client against an arbitrary `Transport`, e.g. a
+    /// `ReplayTransport` serving fixtures in tests.
+    pub fn with_transport(
+        transport: Arc<dyn Transport>,
+        base_url: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        GiteaClient {
+            transport,
+            token: token.into(),
+            base_url: base_url.into(),
+        }
+    }
+
+    fn auth_header(&self) -> String {
+        format!("token {}", self.token)
+    }
+}
+
+#[async_trait]
+impl RemoteGitEngine for GiteaClient {
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: &str,
+        head: &str,
+        base: &str,
+    ) -> Result<SubmitResult> {
+        let url = format!("{}/api/v1/repos/{}/{}/pulls", self.base_url, owner, repo);
+        let payload = json!({ "title": title, "body": body, "head": head, "base": base });
+
+        let request = HttpRequest::new("POST", url)
+            .header("Authorization", self.auth_header())
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to create PR on Gitea")?;
+
+        if response.is_success() {
+            let pr: serde_json::Value = response
+                .json()
+                .context("Failed to parse Gitea PR response")?;
+            let pr_url = pr["html_url"].as_str().unwrap_or("").to_string();
+            Ok(SubmitResult {
+                success: true,
+                action: "PR Created".to_string(),
+                url: Some(pr_url.clone()),
+                message: format!("PR created successfully: {}", pr_url),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "PR Creation Failed".to_string(),
+                url: None,
+                message: format!("Failed to create PR: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn post_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        body: &str,
+    ) -> Result<SubmitResult> {
+        let url = format!(
+            "{}/api/v1/repos/{}/{}/issues/{}/comments",
+            self.base_url, owner, repo, issue_number
+        );
+        let payload = json!({ "body": body });
+
+        let request = HttpRequest::new("POST", url)
+            .header("Authorization", self.auth_header())
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to post comment on Gitea")?;
+
+        if response.is_success() {
+            let comment: serde_json::Value = response
+                .json()
+                .context("Failed to parse Gitea comment response")?;
+            Ok(SubmitResult {
+                success: true,
+                action: "Comment Posted".to_string(),
+                url: comment["html_url"].as_str().map(String::from),
+                message: "Comment posted successfully".to_string(),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "Comment Failed".to_string(),
+                url: None,
+                message: format!("Failed to post comment: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn update_issue_labels_state(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        labels: Option<Vec<&str>>,
+        state: Option<&str>,
+    ) -> Result<SubmitResult> {
+        let url = format!(
+            "{}/api/v1/repos/{}/{}/issues/{}",
+            self.base_url, owner, repo, issue_number
+        );
+        let mut payload = json!({});
+        if let Some(labels) = labels {
+            payload["labels"] = json!(labels);
+        }
+        if let Some(state) = state {
+            payload["state"] = json!(state);
+        }
+
+        let request = HttpRequest::new("PATCH", url)
+            .header("Authorization", self.auth_header())
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to update issue on Gitea")?;
+
+        if response.is_success() {
+            Ok(SubmitResult {
+                success: true,
+                action: "Issue Updated".to_string(),
+                url: None,
+                message: "Issue updated successfully".to_string(),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "Update Failed".to_string(),
+                url: None,
+                message: format!("Failed to update issue: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn submit_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        event: &str,
+        body: &str,
+    ) -> Result<SubmitResult> {
+        let url = format!(
+            "{}/api/v1/repos/{}/{}/pulls/{}/reviews",
+            self.base_url, owner, repo, pr_number
+        );
+        let payload = json!({ "event": event, "body": body });
+
+        let request = HttpRequest::new("POST", url)
+            .header("Authorization", self.auth_header())
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to submit review on Gitea")?;
+
+        if response.is_success() {
+            Ok(SubmitResult {
+                success: true,
+                action: "Review Submitted".to_string(),
+                url: None,
+                message: format!("Review submitted: {}", event),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "Review Failed".to_string(),
+                url: None,
+                message: format!("Failed to submit review: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn list_open_issues(&self, owner: &str, repo: &str) -> Result<Vec<BountyLead>> {
+        let url = format!(
+            "{}/api/v1/repos/{}/{}/issues?state=open&limit=100",
+            self.base_url, owner, repo
+        );
+        let request = HttpRequest::new("GET", url).header("Authorization", self.auth_header());
+        let response: serde_json::Value = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to fetch Gitea issues")?
+            .json()
+            .context("Failed to parse Gitea issues")?;
+
+        let issues = response.as_array().context("Issues should be an array")?;
+        Ok(issues
+            .iter()
+            .filter(|issue| issue.get("pull_request").is_none())
+            .map(|issue| BountyLead {
+                number: issue["number"].as_u64().unwrap_or(0),
+                title: issue["title"].as_str().unwrap_or("").to_string(),
+                body: issue["body"].as_str().unwrap_or("").to_string(),
+                labels: issue["labels"]
+                    .as_array()
+                    .map(|arr| {
+                        arr.iter()
+                            .filter_map(|l| l["name"].as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                reward_estimate: "Unspecified".to_string(),
+                difficulty: "Normal".to_string(),
+                url: issue["html_url"].as_str().unwrap_or("").to_string(),
+                repository: format!("{}/{}", owner, repo),
+                reactions: 0,
+                comments: issue["comments"].as_u64().unwrap_or(0),
+                created_at: issue["created_at"].as_str().unwrap_or("").to_string(),
+            })
+            .collect())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// GitLab
+// ---------------------------------------------------------------------------
+
+/// GitLab exposes the same concepts under different names: pull requests are
+/// `merge_requests`, and both issues and merge requests are addressed by a
+/// per-project `iid` rather than a global number.
+pub struct GitLabClient {
+    transport: Arc<dyn Transport>,
+    token: String,
+    base_url: String,
+}
+
+impl GitLabClient {
+    pub fn new(base_url: impl Into<String>, token: impl Into<String>) -> Self {
+        GitLabClient {
+            transport: Arc::new(ReqwestTransport::new(http::shared_client())),
+            token: token.into(),
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Builds a client against an arbitrary `Transport`, e.g. a
+    /// `ReplayTransport` serving fixtures in tests.
+    pub fn with_transport(
+        transport: Arc<dyn Transport>,
+        base_url: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        GitLabClient {
+            transport,
+            token: token.into(),
+            base_url: base_url.into(),
+        }
+    }
+
+    fn project_path(owner: &str, repo: &str) -> String {
+        urlencoding_path(&format!("{}/{}", owner, repo))
+    }
+}
+
+fn urlencoding_path(s: &str) -> String {
+    s.replace('/', "%2F")
+}
+
+#[async_trait]
+impl RemoteGitEngine for GitLabClient {
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: &str,
+        head: &str,
+        base: &str,
+    ) -> Result<SubmitResult> {
+        let url = format!(
+            "{}/api/v4/projects/{}/merge_requests",
+            self.base_url,
+            Self::project_path(owner, repo)
+        );
+        let payload = json!({
+            "title": title,
+            "description": body,
+            "source_branch": head,
+            "target_branch": base,
+        });
+
+        let request = HttpRequest::new("POST", url)
+            .header("PRIVATE-TOKEN", &self.token)
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to create merge request")?;
+
+        if response.is_success() {
+            let mr: serde_json::Value = response
+                .json()
+                .context("Failed to parse merge request response")?;
+            let mr_url = mr["web_url"].as_str().unwrap_or("").to_string();
+            Ok(SubmitResult {
+                success: true,
+                action: "PR Created".to_string(),
+                url: Some(mr_url.clone()),
+                message: format!("Merge request created successfully: {}", mr_url),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "PR Creation Failed".to_string(),
+                url: None,
+                message: format!("Failed to create merge request: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn post_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        body: &str,
+    ) -> Result<SubmitResult> {
+        let url = format!(
+            "{}/api/v4/projects/{}/issues/{}/notes",
+            self.base_url,
+            Self::project_path(owner, repo),
+            issue_number
+        );
+        let payload = json!({ "body": body });
+
+        let request = HttpRequest::new("POST", url)
+            .header("PRIVATE-TOKEN", &self.token)
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to post note")?;
+
+        if response.is_success() {
+            Ok(SubmitResult {
+                success: true,
+                action: "Comment Posted".to_string(),
+                url: None,
+                message: "Comment posted successfully".to_string(),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "Comment Failed".to_string(),
+                url: None,
+                message: format!("Failed to post comment: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn update_issue_labels_state(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        labels: Option<Vec<&str>>,
+        state: Option<&str>,
+    ) -> Result<SubmitResult> {
+        let url = format!(
+            "{}/api/v4/projects/{}/issues/{}",
+            self.base_url,
+            Self::project_path(owner, repo),
+            issue_number
+        );
+        let mut payload = json!({});
+        if let Some(labels) = labels {
+            payload["labels"] = json!(labels.join(","));
+        }
+        if let Some(state) = state {
+            // GitLab uses state_event (close/reopen) rather than a literal state string.
+            let event = if state == "closed" { "close" } else { "reopen" };
+            payload["state_event"] = json!(event);
+        }
+
+        let request = HttpRequest::new("PUT", url)
+            .header("PRIVATE-TOKEN", &self.token)
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to update issue")?;
+
+        if response.is_success() {
+            Ok(SubmitResult {
+                success: true,
+                action: "Issue Updated".to_string(),
+                url: None,
+                message: "Issue updated successfully".to_string(),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "Update Failed".to_string(),
+                url: None,
+                message: format!("Failed to update issue: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn submit_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        event: &str,
+        body: &str,
+    ) -> Result<SubmitResult> {
+        // GitLab has no first-class review-event concept; approximate it with a note
+        // prefixed with the event name. No approval is recorded on the merge request,
+        // whatever the event.
+        let url = format!(
+            "{}/api/v4/projects/{}/merge_requests/{}/notes",
+            self.base_url,
+            Self::project_path(owner, repo),
+            pr_number
+        );
+        let payload = json!({ "body": format!("[{event}] {body}") });
+
+        let request = HttpRequest::new("POST", url)
+            .header("PRIVATE-TOKEN", &self.token)
+            .json(&payload)?;
+        let response = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to submit review note")?;
+
+        if response.is_success() {
+            Ok(SubmitResult {
+                success: true,
+                action: "Review Submitted".to_string(),
+                url: None,
+                message: format!("Review submitted: {}", event),
+                retry_after_secs: None,
+            })
+        } else {
+            let retry_after = response.retry_after_secs();
+            Ok(SubmitResult {
+                success: false,
+                action: "Review Failed".to_string(),
+                url: None,
+                message: format!("Failed to submit review: {}", response.text()),
+                retry_after_secs: retry_after,
+            })
+        }
+    }
+
+    async fn list_open_issues(&self, owner: &str, repo: &str) -> Result<Vec<BountyLead>> {
+        let url = format!(
+            "{}/api/v4/projects/{}/issues?state=opened&per_page=100",
+            self.base_url,
+            Self::project_path(owner, repo)
+        );
+        let request = HttpRequest::new("GET", url).header("PRIVATE-TOKEN", &self.token);
+        let response: serde_json::Value = http::send_with_retry_via(self.transport.as_ref(), request)
+            .await
+            .context("Failed to fetch GitLab issues")?
+            .json()
+            .context("Failed to parse GitLab issues")?;
+
+        let issues = response.as_array().context("Issues should be an array")?;
+        Ok(issues
+            .iter()
+            .map(|issue| BountyLead {
+                number: issue["iid"].as_u64().unwrap_or(0),
+                title: issue["title"].as_str().unwrap_or("").to_string(),
+                body: issue["description"].as_str().unwrap_or("").to_string(),
+                labels: issue["labels"]
+                    .as_array()
+                    .map(|arr| {
+                        arr.iter()
+                            .filter_map(|l| l.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                reward_estimate: "Unspecified".to_string(),
+                difficulty: "Normal".to_string(),
+                url: issue["web_url"].as_str().unwrap_or("").to_string(),
+                repository: format!("{}/{}", owner, repo),
+                reactions: issue["upvotes"].as_u64().unwrap_or(0),
+                comments: issue["user_notes_count"].as_u64().unwrap_or(0),
+                created_at: issue["created_at"].as_str().unwrap_or("").to_string(),
+            })
+            .collect())
+    }
+}
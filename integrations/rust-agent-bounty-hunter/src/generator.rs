@@ -6,6 +6,29 @@
 //! - Formatting submission summaries
 
 use crate::analyzer::{BountyAnalysis, Complexity};
+use crate::state::ProgressCounts;
+
+/// Renders the "### Fuzzing Plan" section for a claim/PR template, or an
+/// empty string when the bounty has no recommended harness.
+fn fuzzing_plan_section(analysis: &BountyAnalysis) -> String {
+    let Some(plan) = &analysis.fuzzing_plan else {
+        return String::new();
+    };
+
+    let invariants = plan
+        .invariants
+        .iter()
+        .map(|i| format!("- {}", i))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "\n### Fuzzing Plan\n**Harness:** {style}\n\n**Invariants to assert:**\n{invariants}\n\n{notes}\n",
+        style = plan.harness_style.as_str(),
+        invariants = invariants,
+        notes = plan.notes,
+    )
+}
 
 #[derive(Debug, Clone)]
 pub struct ClaimTemplate {
@@ -53,7 +76,7 @@ pub fn generate_claim_comment(
 
 ### Risk Mitigation
 {risks}
-
+{fuzzing_plan}
 ---
 
 I claim this bounty and will submit a PR within the expected timeframe."#,
@@ -70,6 +93,7 @@ I claim this bounty and will submit a PR within the expected timeframe."#,
         } else {
             analysis.risks.join("\n- ")
         },
+        fuzzing_plan = fuzzing_plan_section(analysis),
     );
 
     ClaimTemplate {
@@ -122,7 +146,7 @@ Fix for issue #{issue_number}: {title}
 ### Implementation Notes
 
 {notes}
-
+{fuzzing_plan}
 ---
 
 **Related Issue:** #{issue_number}
@@ -136,6 +160,7 @@ Fix for issue #{issue_number}: {title}
         testing = testing,
         notes = analysis.implementation_notes,
         reward = analysis.estimated_effort,
+        fuzzing_plan = fuzzing_plan_section(analysis),
     )
 }
 
@@ -205,16 +230,14 @@ pub fn generate_claim_template_for_issue(
         risks: Vec::new(),
         dependencies: Vec::new(),
         implementation_notes: "Standard implementation approach".to_string(),
+        fuzzing_plan: None,
+        required_audits: Vec::new(),
     };
 
     generate_claim_comment(number, repo, wallet, handle, &analysis)
 }
 
-pub fn generate_update_for_progress(
-    claimed: u64,
-    in_progress: u64,
-    submitted: u64,
-) -> String {
+pub fn generate_update_for_progress(counts: &ProgressCounts) -> String {
     format!(
         r#"## Progress Update
 
@@ -225,9 +248,9 @@ pub fn generate_update_for_progress(
 | Submitted | {submitted} |
 
 ---
-*Auto-generated progress report"*,
-        claimed = claimed,
-        in_progress = in_progress,
-        submitted = submitted,
+*Auto-generated from the bounty state store*"#,
+        claimed = counts.claimed,
+        in_progress = counts.in_progress,
+        submitted = counts.submitted,
     )
 }
@@ -0,0 +1,180 @@
+//! Shared HTTP plumbing - A single reused client, retried and rate-limit aware
+//!
+//! This module handles:
+//! - Handing out one pooled `reqwest::Client` instead of constructing a new one per call
+//! - Retrying 403/429/5xx responses with GitHub's rate-limit headers honored
+//! - Exponential backoff with jitter when no rate-limit hint is present
+
+use crate::transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport};
+use anyhow::{Context, Result};
+use reqwest::{Response, StatusCode};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Attempts before a request is considered permanently failed.
+pub const MAX_ATTEMPTS: u32 = 3;
+
+static SHARED_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Returns the process-wide pooled client, built once on first use so every
+/// module shares connection pooling instead of paying a fresh TLS handshake
+/// per call.
+pub fn shared_client() -> reqwest::Client {
+    SHARED_CLIENT
+        .get_or_init(|| reqwest::Client::builder().build().unwrap_or_default())
+        .clone()
+}
+
+/// A `Transport` backed by the shared client, for call sites that just want
+/// the real network and have no reason to build their own.
+pub fn default_transport() -> Arc<dyn Transport> {
+    Arc::new(ReqwestTransport::new(shared_client()))
+}
+
+/// Sends `builder`, retrying on 403/429/5xx up to `MAX_ATTEMPTS` times. If the
+/// response carries `X-RateLimit-Remaining: 0`, sleeps until the Unix epoch in
+/// `X-RateLimit-Reset` before retrying; if it carries `Retry-After`, honors
+/// that instead; otherwise falls back to exponential backoff (base 1s,
+/// doubling, with up to 250ms of jitter).
+pub async fn send_with_retry(builder: reqwest::RequestBuilder) -> Result<Response> {
+    let mut attempt = 0u32;
+    let mut backoff = Duration::from_secs(1);
+
+    loop {
+        attempt += 1;
+        let request = builder
+            .try_clone()
+            .context("Request body is not cloneable, cannot retry")?;
+
+        let response = request.send().await.context("Request failed to send")?;
+        let status = response.status();
+
+        let retryable = status == StatusCode::FORBIDDEN
+            || status == StatusCode::TOO_MANY_REQUESTS
+            || status.is_server_error();
+
+        if !retryable || attempt >= MAX_ATTEMPTS {
+            return Ok(response);
+        }
+
+        let wait = rate_limit_wait(&response).unwrap_or_else(|| jittered(backoff, attempt));
+        eprintln!(
+            "   ⏳ HTTP {} on attempt {attempt}/{MAX_ATTEMPTS}, retrying in {:.1}s",
+            status.as_u16(),
+            wait.as_secs_f64()
+        );
+        tokio::time::sleep(wait).await;
+        backoff *= 2;
+    }
+}
+
+fn rate_limit_wait(response: &Response) -> Option<Duration> {
+    if let Some(retry_after) = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+    {
+        return Some(Duration::from_secs(retry_after));
+    }
+
+    let remaining: Option<u64> = response
+        .headers()
+        .get("x-ratelimit-remaining")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok());
+
+    if remaining == Some(0) {
+        let reset: Option<u64> = response
+            .headers()
+            .get("x-ratelimit-reset")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok());
+
+        if let Some(reset) = reset {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            return Some(Duration::from_secs(reset.saturating_sub(now)));
+        }
+    }
+
+    None
+}
+
+/// Adds up to 250ms of jitter to `base`, seeded from the current time, the
+/// process id, and `attempt` so concurrent retriers (and successive retries
+/// within one call) don't all land on the same offset and re-collide.
+fn jittered(base: Duration, attempt: u32) -> Duration {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let seed = (std::process::id() as u64)
+        ^ (attempt as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ nanos as u64;
+    let jitter_ms = (seed % 250) + 1;
+    base + Duration::from_millis(jitter_ms)
+}
+
+/// Same retry policy as `send_with_retry`, but sent through an injectable
+/// `Transport` instead of a `reqwest::RequestBuilder` directly, so callers
+/// can swap in a `ReplayTransport` in tests.
+pub async fn send_with_retry_via(
+    transport: &dyn Transport,
+    request: HttpRequest,
+) -> Result<HttpResponse> {
+    let mut attempt = 0u32;
+    let mut backoff = Duration::from_secs(1);
+
+    loop {
+        attempt += 1;
+        let response = transport.execute(request.clone()).await?;
+        let status = StatusCode::from_u16(response.status).unwrap_or(StatusCode::OK);
+
+        let retryable = status == StatusCode::FORBIDDEN
+            || status == StatusCode::TOO_MANY_REQUESTS
+            || status.is_server_error();
+
+        if !retryable || attempt >= MAX_ATTEMPTS {
+            return Ok(response);
+        }
+
+        let wait = rate_limit_wait_headers(&response).unwrap_or_else(|| jittered(backoff, attempt));
+        eprintln!(
+            "   ⏳ HTTP {} on attempt {attempt}/{MAX_ATTEMPTS}, retrying in {:.1}s",
+            status.as_u16(),
+            wait.as_secs_f64()
+        );
+        tokio::time::sleep(wait).await;
+        backoff *= 2;
+    }
+}
+
+fn rate_limit_wait_headers(response: &HttpResponse) -> Option<Duration> {
+    if let Some(retry_after) = response
+        .header("retry-after")
+        .and_then(|v| v.parse::<u64>().ok())
+    {
+        return Some(Duration::from_secs(retry_after));
+    }
+
+    let remaining: Option<u64> = response
+        .header("x-ratelimit-remaining")
+        .and_then(|v| v.parse().ok());
+
+    if remaining == Some(0) {
+        let reset: Option<u64> = response.header("x-ratelimit-reset").and_then(|v| v.parse().ok());
+
+        if let Some(reset) = reset {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            return Some(Duration::from_secs(reset.saturating_sub(now)));
+        }
+    }
+
+    None
+}
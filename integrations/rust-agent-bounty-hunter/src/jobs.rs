@@ -0,0 +1,141 @@
+//! Job Queue - Retries transient failures around GitHub network calls
+//!
+//! This module handles:
+//! - Wrapping a network operation (fetch, claim, submit) with bounded retries
+//! - Exponential backoff, honoring a `Retry-After` hint when the call provides one
+//! - Forwarding terminal failures onto an error channel instead of aborting the run
+
+use crate::submitter::SubmitResult;
+use anyhow::{anyhow, Context, Result};
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Default number of attempts before a job is considered permanently failed.
+pub const MAX_ATTEMPTS: u32 = 3;
+
+/// Runs `fut` to completion unless `deadline` elapses first, in which case it
+/// is aborted and an error is returned so the caller can record a partial
+/// result instead of hanging indefinitely.
+pub async fn run_with_deadline<F, T>(deadline: Option<Duration>, fut: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    match deadline {
+        Some(d) => tokio::time::timeout(d, fut)
+            .await
+            .map_err(|_| anyhow!("operation exceeded its {:?} deadline", d))?,
+        None => fut.await,
+    }
+}
+
+/// A terminal failure, forwarded onto the error channel so the caller can log it
+/// and record it in the state store rather than crash the whole sweep.
+#[derive(Debug, Clone)]
+pub struct JobFailure {
+    pub repository: String,
+    pub issue_number: u64,
+    pub operation: String,
+    pub message: String,
+}
+
+/// Runs `f`, retrying up to `MAX_ATTEMPTS` times with exponential backoff
+/// (1s, 2s, 4s, ...) on network errors or a `SubmitResult` whose `success` is
+/// false. When the response carries a `Retry-After` hint that is honored
+/// instead of the computed backoff.
+pub async fn run_with_retry<F, Fut>(mut f: F) -> Result<SubmitResult>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<SubmitResult>>,
+{
+    let mut attempt = 0u32;
+    let mut backoff = Duration::from_secs(1);
+
+    loop {
+        attempt += 1;
+        match f().await {
+            Ok(result) if result.success || attempt >= MAX_ATTEMPTS => return Ok(result),
+            Ok(result) => {
+                let wait = result
+                    .retry_after_secs
+                    .map(Duration::from_secs)
+                    .unwrap_or(backoff);
+                eprintln!(
+                    "   ⏳ Retrying after transient failure (attempt {attempt}/{MAX_ATTEMPTS}): {}",
+                    result.message
+                );
+                tokio::time::sleep(wait).await;
+                backoff *= 2;
+            }
+            Err(e) if attempt >= MAX_ATTEMPTS => return Err(e),
+            Err(e) => {
+                eprintln!("   ⏳ Retrying after error (attempt {attempt}/{MAX_ATTEMPTS}): {e}");
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+        }
+    }
+}
+
+/// An mpsc channel of `JobFailure`s, drained by a reporting task so a long
+/// `Auto` sweep can log and record failed bounties without aborting.
+pub struct ErrorChannel {
+    tx: mpsc::UnboundedSender<JobFailure>,
+    drain_task: tokio::task::JoinHandle<()>,
+}
+
+impl ErrorChannel {
+    /// Creates the channel and spawns the reporting task that drains it,
+    /// recording each failure into the state store.
+    pub fn spawn(db_path: String) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<JobFailure>();
+
+        let drain_task = tokio::spawn(async move {
+            let db = match crate::state::DbCtx::open(&db_path) {
+                Ok(db) => db,
+                Err(e) => {
+                    eprintln!("Warning: error-reporting task could not open state db: {e}");
+                    return;
+                }
+            };
+
+            while let Some(failure) = rx.recv().await {
+                eprintln!(
+                    "❌ {} failed for #{} in {}: {}",
+                    failure.operation, failure.issue_number, failure.repository, failure.message
+                );
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_secs() as i64)
+                    .unwrap_or(0);
+                if let Err(e) = db.transition(
+                    &failure.repository,
+                    failure.issue_number,
+                    crate::state::RunState::Failed,
+                    None,
+                    None,
+                    None,
+                    now,
+                ) {
+                    eprintln!("Warning: failed to record job failure: {e}");
+                }
+            }
+        });
+
+        ErrorChannel { tx, drain_task }
+    }
+
+    pub fn report(&self, failure: JobFailure) {
+        let _ = self.tx.send(failure);
+    }
+
+    /// Closes the channel and waits for the draining task to record every
+    /// failure already queued, so a sweep that reports a failure right before
+    /// exiting doesn't race the runtime shutting down underneath it.
+    pub async fn shutdown(self) -> Result<()> {
+        drop(self.tx);
+        self.drain_task
+            .await
+            .context("error-reporting task panicked")
+    }
+}
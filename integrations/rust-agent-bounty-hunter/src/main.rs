@@ -28,19 +28,54 @@
 
 mod scanner;
 mod analyzer;
+mod audit;
+mod corpus;
 mod generator;
 mod quality;
 mod submitter;
+mod state;
+mod webhook;
+mod vcs;
+mod workload;
+mod jobs;
+mod engine;
+mod http;
+mod toolchain;
+mod tokenizer;
+mod transport;
 
 use crate::scanner::{scan_bounties, scan_multiple_repos};
-use crate::analyzer::analyze_bounty;
-use crate::generator::{generate_claim_comment, generate_submission_comment};
+use crate::analyzer::{analyze_bounty, effort_hours_midpoint};
+use crate::audit::AuditStore;
+use crate::corpus::BountyCorpus;
+use crate::engine::GitHubClient;
+use crate::generator::{generate_claim_comment, generate_submission_comment, generate_update_for_progress};
 use crate::quality::validate_submission;
 use crate::submitter::{claim_bounty, submit_bounty_completion};
+use crate::state::{DbCtx, RunState};
+use crate::webhook::{serve, ServeConfig, WebhookSource};
+use crate::workload::{run_workload, Workload};
+use crate::{submitter, vcs};
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
-use std::collections::HashMap;
 use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default location for the bounty lifecycle state database.
+const DEFAULT_DB_PATH: &str = "bounty_state.db";
+
+/// Default location for the TF-IDF corpus of previously analyzed bounties.
+const DEFAULT_CORPUS_PATH: &str = "bounty_corpus.json";
+
+/// Default location for the cargo-vet-style dependency audit store.
+const DEFAULT_AUDIT_PATH: &str = "bounty_audits.toml";
+
+fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
 
 const VERSION: &str = "1.0.0";
 
@@ -57,6 +92,20 @@ struct Args {
     /// GitHub Authentication Token
     #[arg(short, long, env = "GITHUB_TOKEN")]
     token: Option<String>,
+
+    /// Path to the bounty lifecycle state database
+    #[arg(long, default_value = DEFAULT_DB_PATH)]
+    db_path: String,
+
+    /// Path to the TF-IDF corpus of previously analyzed bounties, used to
+    /// refine complexity/effort estimates as more bounties are recorded
+    #[arg(long, default_value = DEFAULT_CORPUS_PATH)]
+    corpus_path: String,
+
+    /// Path to the cargo-vet-style dependency audit store (TOML), used to
+    /// flag unaudited or known-vulnerable crates a bounty would introduce
+    #[arg(long, default_value = DEFAULT_AUDIT_PATH)]
+    audit_path: String,
 }
 
 #[derive(Subcommand, Debug)]
@@ -117,9 +166,9 @@ enum Commands {
         /// Repository (owner/repo format)
         #[arg(short, long)]
         repo: String,
-        /// PR URL
+        /// PR URL; omit with --generate-pr to have the agent open one itself
         #[arg(short, long)]
-        pr: String,
+        pr: Option<String>,
         /// Wallet address for reward
         #[arg(short, long)]
         wallet: String,
@@ -132,6 +181,16 @@ enum Commands {
         /// Actually submit (dry-run by default)
         #[arg(short, long)]
         post: bool,
+        /// Clone, branch, commit the working tree, push, and open the PR instead of
+        /// requiring a pre-existing --pr URL
+        #[arg(long)]
+        generate_pr: bool,
+        /// Clone URL to use with --generate-pr
+        #[arg(long)]
+        clone_url: Option<String>,
+        /// Base branch to open the PR against
+        #[arg(long, default_value = "main")]
+        base_branch: String,
     },
 
     /// Check submission quality
@@ -144,6 +203,46 @@ enum Commands {
         pr: u64,
     },
 
+    /// Print the current state of tracked bounties
+    Status {
+        /// Only show bounties in this repository (owner/repo format)
+        #[arg(short, long)]
+        repo: Option<String>,
+    },
+
+    /// Drive the pipeline from a declarative workload file
+    Run {
+        /// Path to a JSON workload file
+        #[arg(short, long)]
+        workload: String,
+        /// Write the JSON result report here instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Run a webhook daemon that reacts to GitHub issue events
+    Serve {
+        /// Address to bind the webhook HTTP server to
+        #[arg(short, long, default_value = "0.0.0.0:8080")]
+        addr: String,
+        /// Webhook source as `secret` or `secret:allowed_sender`; repeat to let
+        /// several repos/teams share this one endpoint with distinct secrets
+        #[arg(long = "webhook-source", env = "WEBHOOK_SECRET", required = true)]
+        webhook_sources: Vec<String>,
+        /// Repositories (owner/repo) to react to; defaults to all
+        #[arg(long = "watch-repo")]
+        watch_repos: Vec<String>,
+        /// Wallet address to use for auto-claims
+        #[arg(short, long)]
+        wallet: Option<String>,
+        /// GitHub handle to use for auto-claims
+        #[arg(long)]
+        handle: Option<String>,
+        /// Automatically post claims for matching events (off by default)
+        #[arg(long)]
+        auto_claim: bool,
+    },
+
     /// Full workflow: scan, analyze, claim, implement, submit
     Auto {
         /// Repository owner
@@ -161,6 +260,14 @@ enum Commands {
         /// Automatically submit PRs (highly recommended: use --dry-run first)
         #[arg(long)]
         dry_run: bool,
+        /// Bound the scan, and each bounty's claim including its retries, to this
+        /// many seconds; a claim that exceeds it is reported as failed and the
+        /// sweep moves on, while a scan that exceeds it ends the run with an error
+        #[arg(long)]
+        deadline: Option<u64>,
+        /// Restart the sweep from scratch even if a checkpoint looks stuck
+        #[arg(long)]
+        force: bool,
     },
 }
 
@@ -174,20 +281,30 @@ async fn main() -> Result<()> {
         None => env::var("GITHUB_TOKEN").context("GITHUB_TOKEN not set. Use --token or set GITHUB_TOKEN env var.")?
     };
 
+    let db = DbCtx::open(&args.db_path).context("Failed to open bounty state database")?;
+    let github = GitHubClient::new(token.clone());
+    let transport = http::default_transport();
+    let mut corpus =
+        BountyCorpus::load_or_empty(&args.corpus_path).context("Failed to load bounty corpus")?;
+    let audits =
+        AuditStore::load_or_empty(&args.audit_path).context("Failed to load dependency audit store")?;
+
     match args.command {
         Commands::Scan { owner, repo, output, top } => {
             println!("🔍 Scanning for bounties...");
-            
+
             let bounties = if let (Some(o), Some(r)) = (owner, repo) {
-                scan_bounties(&o, &r, &token).await?
+                scan_bounties(&o, &r, &token, transport.as_ref()).await?
             } else {
                 // Default repos
-                let mut repos = HashMap::new();
-                repos.insert("Scottcjn", "rustchain-bounties");
-                repos.insert("rustchain", "rustchain");
-                scan_multiple_repos(repos, &token).await?
+                let repos = vec![("Scottcjn", "rustchain-bounties"), ("rustchain", "rustchain")];
+                scan_multiple_repos(repos, &token, transport.clone()).await?
             };
 
+            for bounty in &bounties {
+                db.record_discovered(&bounty.repository, bounty.number, now_unix())?;
+            }
+
             println!("\n📊 Found {} bounty opportunities:", bounties.len());
             for (i, bounty in bounties.iter().take(top as usize).enumerate() {
                 println!("\n{}. #{} - {}", i + 1, bounty.number, bounty.title);
@@ -206,26 +323,27 @@ async fn main() -> Result<()> {
         Commands::Analyze { owner, repo, issue } => {
             println!("📊 Analyzing issue #{issue}...");
 
-            let client = reqwest::Client::new();
+            let client = http::shared_client();
             let url = format!(
                 "https://api.github.com/repos/{}/{}/issues/{}",
                 owner, repo, issue
             );
 
-            let issue_data: serde_json::Value = client
-                .get(&url)
-                .header("Authorization", format!("token {}", token))
-                .send()
-                .await
-                .context("Failed to fetch issue")?
-                .json()
-                .await
-                .context("Failed to parse issue")?;
+            let issue_data: serde_json::Value = http::send_with_retry(
+                client
+                    .get(&url)
+                    .header("Authorization", format!("token {}", token)),
+            )
+            .await
+            .context("Failed to fetch issue")?
+            .json()
+            .await
+            .context("Failed to parse issue")?;
 
             let title = issue_data["title"].as_str().unwrap_or("");
             let body = issue_data["body"].as_str().unwrap_or("");
 
-            let analysis = analyze_bounty(issue, title, body);
+            let analysis = analyze_bounty(issue, title, body, Some(&corpus), Some(&audits));
 
             println!("\n📋 Analysis for Issue #{}", analysis.number);
             println!("   Title: {}", analysis.title);
@@ -239,6 +357,13 @@ async fn main() -> Result<()> {
                     println!("   - {}", risk);
                 }
             }
+
+            if !analysis.required_audits.is_empty() {
+                println!("\n🔍 Audits required before this can close:");
+                for audit in &analysis.required_audits {
+                    println!("   - {}", audit);
+                }
+            }
         }
 
         Commands::Claim { issue, repo, wallet, handle, post } => {
@@ -247,64 +372,175 @@ async fn main() -> Result<()> {
             let parts: Vec<&str> = repo.split('/').collect();
             let (owner, repo_name) = (parts[0], parts[1]);
 
-            let client = reqwest::Client::new();
+            let client = http::shared_client();
             let url = format!(
                 "https://api.github.com/repos/{}/{}/issues/{}",
                 owner, repo_name, issue
             );
 
-            let issue_data: serde_json::Value = client
-                .get(&url)
-                .header("Authorization", format!("token {}", token))
-                .send()
-                .await
-                .context("Failed to fetch issue")?
-                .json()
-                .await
-                .context("Failed to parse issue")?;
+            let issue_data: serde_json::Value = http::send_with_retry(
+                client
+                    .get(&url)
+                    .header("Authorization", format!("token {}", token)),
+            )
+            .await
+            .context("Failed to fetch issue")?
+            .json()
+            .await
+            .context("Failed to parse issue")?;
 
             let title = issue_data["title"].as_str().unwrap_or("");
             let body = issue_data["body"].as_str().unwrap_or("");
 
-            let analysis = analyze_bounty(issue, title, body);
+            if db.is_already_claimed(&repo, issue)? {
+                println!("\n⚠️  Issue #{issue} is already claimed according to the state store");
+                return Ok(());
+            }
+
+            let analysis = analyze_bounty(issue, title, body, Some(&corpus), Some(&audits));
             let template = generate_claim_comment(issue, &repo, &wallet, &handle, &analysis);
 
             if post {
-                let result = claim_bounty(owner, repo_name, issue, &template.body, "claimed", &token).await?;
+                let result = claim_bounty(&github, owner, repo_name, issue, &template.body, "claimed").await?;
                 println!("\n✅ {}", result.message);
                 if let Some(url) = result.url {
                     println!("   URL: {}", url);
                 }
+                if result.success {
+                    db.transition(&repo, issue, RunState::Claimed, Some(&wallet), Some(&handle), None, now_unix())?;
+                } else {
+                    db.transition(&repo, issue, RunState::Failed, Some(&wallet), Some(&handle), None, now_unix())?;
+                }
             } else {
                 println!("\n📝 Claim Template (--post to submit):");
                 println!("{}", template.body);
             }
         }
 
-        Commands::Submit { issue, repo, pr, wallet, handle, summary, post } => {
+        Commands::Submit { issue, repo, pr, wallet, handle, summary, post, generate_pr, clone_url, base_branch } => {
             println!("📤 Generating submission for issue #{issue}...");
 
             let parts: Vec<&str> = repo.split('/').collect();
             let (owner, repo_name) = (parts[0], parts[1]);
 
-            let template = generate_submission_comment(issue, &repo, &pr, &wallet, &handle, &summary);
+            let pr_url = if let Some(pr) = pr {
+                pr
+            } else if generate_pr {
+                let clone_url = clone_url
+                    .context("--clone-url is required when using --generate-pr without --pr")?;
+                let work_dir = std::env::temp_dir().join(format!("bounty-fix-{issue}"));
+                let analysis = analyze_bounty(issue, &summary, &summary, Some(&corpus), Some(&audits));
+                let auth = vcs::VcsAuth::default_ssh_key()?;
+                let result = submitter::generate_and_submit_pr(
+                    owner, repo_name, &clone_url, &work_dir, &base_branch, &analysis, &summary, &auth, &token,
+                ).await?;
+                result.url.context("PR generation did not return a URL")?
+            } else {
+                anyhow::bail!("Either --pr or --generate-pr (with --clone-url) must be supplied");
+            };
+
+            let template = generate_submission_comment(issue, &repo, &pr_url, &wallet, &handle, &summary);
 
             if post {
-                let result = submit_bounty_completion(owner, repo_name, issue, &pr, &template.body, &token).await?;
+                let result = submit_bounty_completion(&github, owner, repo_name, issue, &pr_url, &template.body).await?;
                 println!("\n✅ {}", result.message);
+                let state = if result.success { RunState::Submitted } else { RunState::Failed };
+                db.transition(&repo, issue, state, Some(&wallet), Some(&handle), Some(&pr_url), now_unix())?;
+
+                // A successful submission is a confirmed data point - record it so
+                // later `estimate` calls have one more neighbor to draw on.
+                if result.success {
+                    let analysis = analyze_bounty(issue, &summary, &summary, Some(&corpus), Some(&audits));
+                    let tokens = tokenizer::tokenize(&format!("{} {}", summary, summary));
+                    let hours = effort_hours_midpoint(&analysis.technical_complexity);
+                    corpus.add(&tokens, analysis.technical_complexity, hours);
+                    corpus.save(&args.corpus_path).context("Failed to save bounty corpus")?;
+                }
             } else {
                 println!("\n📝 Submission Template (--post to submit):");
                 println!("{}", template.body);
             }
         }
 
+        Commands::Status { repo } => {
+            println!("📊 Bounty State Store");
+
+            let records = db.list_all()?;
+            let records: Vec<_> = records
+                .into_iter()
+                .filter(|r| repo.as_deref().map(|f| f == r.repository).unwrap_or(true))
+                .collect();
+
+            if records.is_empty() {
+                println!("\n(no tracked bounties yet)");
+            } else {
+                for r in &records {
+                    println!(
+                        "\n#{} - {} [{}]",
+                        r.issue_number,
+                        r.repository,
+                        r.state.as_str()
+                    );
+                    if let Some(handle) = &r.github_handle {
+                        println!("   Claimant: @{}", handle);
+                    }
+                    if let Some(pr_url) = &r.pr_url {
+                        println!("   PR: {}", pr_url);
+                    }
+                }
+            }
+
+            println!("\n{}", generate_update_for_progress(&db.progress_counts()?));
+        }
+
+        Commands::Run { workload, output } => {
+            let workload = Workload::load(&workload)?;
+            println!("🧾 Running workload '{}' ({} repo(s))", workload.name, workload.repos.len());
+
+            let report = run_workload(&workload, &token, Some(&corpus), Some(&audits)).await?;
+            let json = serde_json::to_string_pretty(&report)?;
+
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, &json)?;
+                    println!("✅ Report written to {}", path);
+                }
+                None => println!("{}", json),
+            }
+        }
+
+        Commands::Serve { addr, webhook_sources, watch_repos, wallet, handle, auto_claim } => {
+            let sources = webhook_sources
+                .into_iter()
+                .map(|raw| match raw.split_once(':') {
+                    Some((secret, sender)) => WebhookSource {
+                        secret: secret.to_string(),
+                        allowed_sender: Some(sender.to_string()),
+                    },
+                    None => WebhookSource {
+                        secret: raw,
+                        allowed_sender: None,
+                    },
+                })
+                .collect();
+
+            let config = ServeConfig {
+                sources,
+                watched_repos: watch_repos,
+                wallet: wallet.unwrap_or_default(),
+                handle: handle.unwrap_or_default(),
+                auto_claim,
+            };
+            serve(config, token, db, corpus, audits, &addr).await?;
+        }
+
         Commands::Validate { repo, pr } => {
             println!("🔍 Validating PR #{}...", pr);
 
             let parts: Vec<&str> = repo.split('/').collect();
             let (owner, repo_name) = (parts[0], parts[1]);
 
-            let report = validate_submission(pr, &repo, &token).await?;
+            let report = validate_submission(pr, &repo, &token, transport.as_ref()).await?;
             
             println!("\n📊 Quality Report for PR #{}", pr);
             println!("   {}", report.summary());
@@ -315,52 +551,123 @@ async fn main() -> Result<()> {
             }
         }
 
-        Commands::Auto { owner, repo, wallet, handle, dry_run } => {
+        Commands::Auto { owner, repo, wallet, handle, dry_run, deadline, force } => {
             println!("🚀 Starting auto bounty hunter...");
             println!("   Target: {}/{}", owner, repo);
             println!("   Mode: {}", if dry_run { "DRY RUN" } else { "LIVE" });
-            
+
             if dry_run {
                 println!("\n⚠️  Dry run mode - no actual changes will be made\n");
             }
 
+            let deadline = deadline.map(std::time::Duration::from_secs);
+            let repo_full = format!("{}/{}", owner, repo);
+
+            // Resume support: a prior sweep that never finished left a checkpoint
+            // with the last issue number it fully processed. That number is reported
+            // and carried into the new checkpoint unless --force says to start clean
+            // (e.g. the prior run is stuck); which bounties get processed is decided
+            // by the state store below, not by this number.
+            let resume_from = match db.load_auto_checkpoint(&repo_full)? {
+                Some((last_issue, running)) if running && !force => {
+                    println!("\n↻ Resuming sweep from issue #{last_issue} (use --force to restart)");
+                    Some(last_issue)
+                }
+                _ => None,
+            };
+            db.start_auto_checkpoint(&repo_full, resume_from.unwrap_or(0), now_unix())?;
+
             // Step 1: Scan
             println!("\n1️⃣  Scanning for bounties...");
-            let bounties = scan_bounties(&owner, &repo, &token).await?;
+            let bounties = jobs::run_with_deadline(
+                deadline,
+                scan_bounties(&owner, &repo, &token, transport.as_ref()),
+            )
+            .await?;
             println!("   Found {} bounties", bounties.len());
 
-            // Step 2: Pick top bounty
-            if let Some(first_bounty) = bounties.first() {
-                println!("\n2️⃣  Selecting top bounty: #{} - {}", first_bounty.number, first_bounty.title);
-                
-                // Analyze
+            for bounty in &bounties {
+                db.record_discovered(&bounty.repository, bounty.number, now_unix())?;
+            }
+
+            // Step 2: Work through every bounty that isn't already claimed. Bounties
+            // are scanned in score order, not numeric order, so "resume" can't be a
+            // numeric watermark over `b.number` — a crash right after a high-numbered
+            // but high-scored bounty would then silently skip every lower-numbered one
+            // still waiting. The state store's claimed/in-progress/submitted status is
+            // the only reliable record of what was actually finished, so that's the
+            // sole gate; `resume_from` is kept just to report where a prior sweep left off.
+            let unclaimed: Vec<_> = bounties
+                .iter()
+                .filter(|b| !db.is_already_claimed(&repo_full, b.number).unwrap_or(false))
+                .collect();
+
+            if unclaimed.is_empty() {
+                println!("\n⚠️  No bounties found to claim");
+            }
+
+            let errors = jobs::ErrorChannel::spawn(args.db_path.clone());
+
+            for bounty in unclaimed {
+                println!("\n2️⃣  Selecting bounty: #{} - {}", bounty.number, bounty.title);
+
                 println!("3️⃣  Analyzing...");
-                let analysis = analyze_bounty(first_bounty.number, &first_bounty.title, &first_bounty.body);
+                let analysis = analyze_bounty(bounty.number, &bounty.title, &bounty.body, Some(&corpus), Some(&audits));
                 println!("   Complexity: {}", analysis.technical_complexity.as_str());
                 println!("   Effort: {}", analysis.estimated_effort);
 
-                // Generate claim
                 println!("4️⃣  Generating claim...");
-                let claim = generate_claim_comment(
-                    first_bounty.number, 
-                    &format!("{}/{}", owner, repo), 
-                    &wallet, 
-                    &handle, 
-                    &analysis
-                );
+                let claim = generate_claim_comment(bounty.number, &repo_full, &wallet, &handle, &analysis);
 
                 if dry_run {
-                    println!("\n📝 Would claim bounty #{}", first_bounty.number);
+                    println!("\n📝 Would claim bounty #{}", bounty.number);
                     println!("{}", claim.body);
-                } else {
-                    let result = claim_bounty(
-                        &owner, &repo, first_bounty.number, &claim.body, "claimed", &token
-                    ).await?;
-                    println!("\n✅ Claim submitted: {}", result.message);
+                    db.advance_auto_checkpoint(&repo_full, bounty.number, now_unix())?;
+                    continue;
                 }
-            } else {
-                println!("\n⚠️  No bounties found to claim");
+
+                let claim_result = jobs::run_with_deadline(
+                    deadline,
+                    jobs::run_with_retry(|| {
+                        claim_bounty(&github, &owner, &repo, bounty.number, &claim.body, "claimed")
+                    }),
+                )
+                .await;
+
+                match claim_result {
+                    Ok(result) => {
+                        let state = if result.success { RunState::Claimed } else { RunState::Failed };
+                        db.transition(&repo_full, bounty.number, state, Some(&wallet), Some(&handle), None, now_unix())?;
+                        if result.success {
+                            println!("\n✅ Claim submitted: {}", result.message);
+                        } else {
+                            errors.report(jobs::JobFailure {
+                                repository: repo_full.clone(),
+                                issue_number: bounty.number,
+                                operation: "claim".to_string(),
+                                message: result.message,
+                            });
+                        }
+                    }
+                    Err(e) => {
+                        errors.report(jobs::JobFailure {
+                            repository: repo_full.clone(),
+                            issue_number: bounty.number,
+                            operation: "claim".to_string(),
+                            message: e.to_string(),
+                        });
+                    }
+                }
+
+                db.advance_auto_checkpoint(&repo_full, bounty.number, now_unix())?;
             }
+
+            // Drain any failures still queued before the runtime tears down,
+            // so a terminal failure reported right before the sweep ends is
+            // still recorded rather than silently dropped.
+            errors.shutdown().await?;
+
+            db.finish_auto_checkpoint(&repo_full, now_unix())?;
         }
     }
 
@@ -6,7 +6,11 @@
 //! - Validating documentation completeness
 //! - Ensuring commit history cleanliness
 
-use anyhow::{Result, anyhow};
+use crate::http;
+use crate::toolchain;
+use crate::transport::{HttpRequest, Transport};
+use crate::vcs::VcsAuth;
+use anyhow::{Context, Result};
 use std::path::Path;
 
 #[derive(Debug, Clone)]
@@ -36,7 +40,11 @@ impl QualityReport {
     }
 
     pub fn summary(&self) -> String {
-        let status = if self.passed { "✅ PASSED" } else { "❌ FAILED" };
+        let status = if self.passed {
+            "✅ PASSED"
+        } else {
+            "❌ FAILED"
+        };
         format!(
             "{} ({:.1}% - {}/{} points)",
             status,
@@ -47,27 +55,21 @@ impl QualityReport {
     }
 }
 
-pub fn validate_submission(
+pub async fn validate_submission(
     pr_number: u64,
     repo: &str,
     github_token: &str,
+    transport: &dyn Transport,
 ) -> Result<QualityReport> {
-    let client = reqwest::Client::new();
-    
     // Get PR details
-    let pr_url = format!(
-        "https://api.github.com/repos/{}/pulls/{}",
-        repo, pr_number
-    );
-    
-    let pr_response = client
-        .get(&pr_url)
-        .header("Authorization", format!("token {}", github_token))
-        .send()
+    let pr_url = format!("https://api.github.com/repos/{}/pulls/{}", repo, pr_number);
+    let pr_request =
+        HttpRequest::new("GET", pr_url).header("Authorization", format!("token {}", github_token));
+
+    let pr_response = http::send_with_retry_via(transport, pr_request)
         .await
         .context("Failed to fetch PR")?
         .json::<serde_json::Value>()
-        .await
         .context("Failed to parse PR")?;
 
     // Get PR files
@@ -75,18 +77,17 @@ pub fn validate_submission(
         "https://api.github.com/repos/{}/pulls/{}/files",
         repo, pr_number
     );
-    
-    let files_response = client
-        .get(&files_url)
-        .header("Authorization", format!("token {}", github_token))
-        .send()
+    let files_request = HttpRequest::new("GET", files_url)
+        .header("Authorization", format!("token {}", github_token));
+
+    let files_response = http::send_with_retry_via(transport, files_request)
         .await
         .context("Failed to fetch PR files")?
         .json::<serde_json::Value>()
-        .await
         .context("Failed to parse PR files")?;
 
-    let files = files_response.as_array()
+    let files = files_response
+        .as_array()
         .context("Files should be an array")?;
 
     // Run quality checks
@@ -95,7 +96,10 @@ pub fn validate_submission(
     let mut max_score = 0u64;
 
     // Check 1: PR has description
-    let has_description = pr_response.get("body").map(|b| !b.is_null() && b.as_str().map(|s| !s.is_empty()).unwrap_or(false)).unwrap_or(false);
+    let has_description = pr_response
+        .get("body")
+        .map(|b| !b.is_null() && b.as_str().map(|s| !s.is_empty()).unwrap_or(false))
+        .unwrap_or(false);
     let desc_check = QualityCheck {
         name: "PR Description".to_string(),
         passed: has_description,
@@ -182,13 +186,33 @@ pub fn validate_submission(
         message: if is_mergeable {
             format!("PR is mergeable (state: {})", review_state)
         } else {
-            format!("⚠️ PR has merge conflicts or needs rebasing (state: {})", review_state)
+            format!(
+                "⚠️ PR has merge conflicts or needs rebasing (state: {})",
+                review_state
+            )
         },
     };
     checks.push(merge_check.clone());
     total_score += merge_check.score;
     max_score += merge_check.max_score;
 
+    // Check 6: actually build, lint, and test the PR's branch rather than
+    // guessing from filenames alone.
+    let head_clone_url = pr_response["head"]["repo"]["clone_url"].as_str();
+    let head_branch = pr_response["head"]["ref"].as_str();
+    if let (Some(clone_url), Some(branch)) = (head_clone_url, head_branch) {
+        let auth = VcsAuth::HttpsToken {
+            user: "x-access-token".to_string(),
+            token: github_token.to_string(),
+        };
+        let toolchain_report = toolchain::run_toolchain_checks(clone_url, branch, &auth)?;
+        for check in toolchain_report.checks {
+            total_score += check.score;
+            max_score += check.max_score;
+            checks.push(check);
+        }
+    }
+
     let passed = total_score >= max_score / 2;
 
     Ok(QualityReport {
@@ -199,26 +223,15 @@ pub fn validate_submission(
     })
 }
 
+/// Runs the real fmt/clippy/test suite against an already-checked-out directory,
+/// e.g. one `vcs::clone_repo` already prepared for a local review.
 pub fn check_code_quality(file_path: &Path) -> Result<QualityReport> {
-    // This would integrate with rustfmt, clippy, etc.
-    // For now, return a placeholder
-    Ok(QualityReport {
-        passed: true,
-        score: 100,
-        max_score: 100,
-        checks: vec![QualityCheck {
-            name: "Code Quality".to_string(),
-            passed: true,
-            score: 100,
-            max_score: 100,
-            message: "Manual review required".to_string(),
-        }],
-    })
+    toolchain::run_checks_in_dir(file_path)
 }
 
 pub fn validate_commit_history(commits: &[serde_json::Value]) -> Result<QualityReport> {
     let commit_count = commits.len();
-    
+
     let has_good_messages = commits.iter().all(|c| {
         let msg = c["commit"]["message"].as_str().unwrap_or("");
         msg.len() > 10 && !msg.starts_with("Merge")
@@ -243,3 +256,36 @@ pub fn validate_commit_history(commits: &[serde_json::Value]) -> Result<QualityR
 
     Ok(report)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::ReplayTransport;
+
+    #[tokio::test]
+    async fn validate_submission_scores_pr_and_files() {
+        let transport = ReplayTransport::load(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/validate_submission.json"
+        ))
+        .expect("failed to load fixture");
+
+        let report = validate_submission(42, "acme/widget", "test-token", &transport)
+            .await
+            .expect("validate_submission should succeed against the fixture");
+
+        // No `head` branch is present in the fixture's PR response, so the
+        // toolchain-checks step is skipped and the score only reflects the
+        // filename/body heuristics below.
+        assert_eq!(report.score, 50);
+        assert_eq!(report.max_score, 55);
+        assert!(report.passed);
+
+        let by_name = |name: &str| report.checks.iter().find(|c| c.name == name).unwrap();
+        assert!(by_name("PR Description").passed);
+        assert!(by_name("Tests Included").passed);
+        assert!(!by_name("Documentation Updated").passed);
+        assert!(by_name("Contains Code").passed);
+        assert!(by_name("Merge Ready").passed);
+    }
+}
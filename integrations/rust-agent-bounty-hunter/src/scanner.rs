@@ -1,13 +1,23 @@
 //! Bounty Scanner - Fetches and ranks open bounty leads from GitHub
 //!
 //! This module handles:
-//! - Fetching issues with bounty-related labels
-//! - Ranking by difficulty and reward potential
+//! - Fetching issues with bounty-related labels via the GraphQL API, paging
+//!   through `after`/`endCursor` until `hasNextPage` is false
+//! - Ranking by a weighted combination of reward, difficulty, and engagement
 //! - Filtering by repository and status
 
-use anyhow::{Result, Context};
-use serde::Deserialize;
-use std::collections::HashMap;
+use crate::http;
+use crate::transport::{HttpRequest, Transport};
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde_json::json;
+use std::sync::{Arc, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::Semaphore;
+
+/// How many repos `scan_multiple_repos` will fetch at once, so a large
+/// workload doesn't fire dozens of simultaneous requests at a forge's API.
+const MAX_CONCURRENT_SCANS: usize = 4;
 
 #[derive(Debug, Clone)]
 pub struct BountyLead {
@@ -19,82 +29,194 @@ pub struct BountyLead {
     pub difficulty: String,
     pub url: String,
     pub repository: String,
+    /// Total reactions on the issue, from GraphQL's `reactions.totalCount`.
+    pub reactions: u64,
+    /// Total comments on the issue, from GraphQL's `comments.totalCount`.
+    pub comments: u64,
+    /// ISO 8601 creation timestamp, from GraphQL's `createdAt`.
+    pub created_at: String,
 }
 
 impl BountyLead {
+    /// Weighs the parsed RTC reward amount by a difficulty multiplier and adds
+    /// a staleness/engagement bonus, so sorting by score produces a ranking
+    /// that actually reflects how worthwhile a bounty is.
     pub fn score(&self) -> u64 {
-        // Simple scoring: higher reward = higher score
-        let reward_score = match self.reward_estimate.contains("100") {
-            true => 100,
-            true => 80,
-            true => 50,
-            _ => 20,
-        };
-        reward_score
+        let reward_amount = parse_reward_amount(&self.reward_estimate);
+        let weight = difficulty_weight(&self.difficulty);
+        let engagement = self.reactions.saturating_add(self.comments);
+        let staleness = staleness_factor(&self.created_at);
+
+        reward_amount
+            .saturating_mul(weight)
+            .saturating_add(engagement.saturating_mul(2))
+            .saturating_add(staleness)
+    }
+}
+
+/// Pulls the first run of digits out of a reward string like `"100+ RTC"`.
+fn parse_reward_amount(reward_estimate: &str) -> u64 {
+    static DIGITS_RE: OnceLock<Regex> = OnceLock::new();
+    let re = DIGITS_RE.get_or_init(|| Regex::new(r"\d+").expect("valid regex"));
+    re.find(reward_estimate)
+        .and_then(|m| m.as_str().parse::<u64>().ok())
+        .unwrap_or(0)
+}
+
+fn difficulty_weight(difficulty: &str) -> u64 {
+    match difficulty {
+        "Critical" => 5,
+        "High" => 3,
+        "Medium" => 2,
+        _ => 1,
+    }
+}
+
+/// Older issues that still have engagement are more likely to be genuinely
+/// stuck than simply new; age is capped at 30 days so ancient, abandoned
+/// issues don't dominate the ranking purely on staleness.
+fn staleness_factor(created_at: &str) -> u64 {
+    let Some(created_unix) = parse_iso8601_to_unix(created_at) else {
+        return 0;
+    };
+    let age_days = now_unix().saturating_sub(created_unix) / 86_400;
+    age_days.clamp(0, 30) as u64
+}
+
+fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Parses a GraphQL `createdAt` timestamp (`YYYY-MM-DDTHH:MM:SSZ`) into Unix
+/// seconds without pulling in a date/time crate.
+fn parse_iso8601_to_unix(ts: &str) -> Option<i64> {
+    if ts.len() < 19 {
+        return None;
+    }
+    let year: i64 = ts.get(0..4)?.parse().ok()?;
+    let month: i64 = ts.get(5..7)?.parse().ok()?;
+    let day: i64 = ts.get(8..10)?.parse().ok()?;
+    let hour: i64 = ts.get(11..13)?.parse().ok()?;
+    let minute: i64 = ts.get(14..16)?.parse().ok()?;
+    let second: i64 = ts.get(17..19)?.parse().ok()?;
+
+    let days = days_from_civil(year, month, day);
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second)
+}
+
+/// Howard Hinnant's `days_from_civil`: days since the Unix epoch for a
+/// proleptic Gregorian calendar date.
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (m + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+const ISSUES_QUERY: &str = r#"
+query($owner: String!, $repo: String!, $cursor: String) {
+  repository(owner: $owner, name: $repo) {
+    issues(first: 100, after: $cursor, states: OPEN) {
+      pageInfo { hasNextPage endCursor }
+      nodes {
+        number
+        title
+        body
+        url
+        createdAt
+        comments { totalCount }
+        reactions { totalCount }
+        labels(first: 20) { nodes { name } }
+      }
     }
+  }
 }
+"#;
 
 pub async fn scan_bounties(
     owner: &str,
     repo: &str,
     github_token: &str,
+    transport: &dyn Transport,
 ) -> Result<Vec<BountyLead>> {
-    let client = reqwest::Client::new();
-    
-    // Fetch open issues with bounty labels
-    let url = format!(
-        "https://api.github.com/repos/{}/{}/issues?state=open&per_page=100",
-        owner, repo
-    );
-    
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("token {}", github_token))
-        .header("Accept", "application/vnd.github.v3+json")
-        .send()
-        .await
-        .context("Failed to fetch issues")?
-        .json::<serde_json::Value>()
-        .await
-        .context("Failed to parse issues")?;
-
-    let issues = response.as_array()
-        .context("Issues should be an array")?;
-
     let mut bounties: Vec<BountyLead> = Vec::new();
+    let mut cursor: Option<String> = None;
 
-    for issue in issues {
-        let labels = issue["labels"]
-            .as_array()
-            .map(|arr| arr.iter().filter_map(|l| l["name"].as_str().map(String::from)).collect())
-            .unwrap_or_default();
+    loop {
+        let payload = json!({
+            "query": ISSUES_QUERY,
+            "variables": { "owner": owner, "repo": repo, "cursor": cursor },
+        });
+
+        let request = HttpRequest::new("POST", "https://api.github.com/graphql")
+            .header("Authorization", format!("Bearer {}", github_token))
+            .json(&payload)?;
 
-        // Skip PRs
-        if issue.get("pull_request").is_some() {
-            continue;
+        let response: serde_json::Value = http::send_with_retry_via(transport, request)
+            .await
+            .context("Failed to query GitHub GraphQL API")?
+            .json()
+            .context("Failed to parse GraphQL response")?;
+
+        if let Some(errors) = response.get("errors") {
+            bail!("GitHub GraphQL API returned errors: {errors}");
         }
 
-        // Check for bounty-related labels
-        let has_bounty_label = labels.iter().any(|l| 
-            l.to_lowercase().contains("bounty") || 
-            l.to_lowercase().contains("reward") ||
-            l.to_lowercase().contains("paid")
-        );
+        let issues = &response["data"]["repository"]["issues"];
+        let nodes = issues["nodes"]
+            .as_array()
+            .context("GraphQL response missing issue nodes")?;
+
+        for issue in nodes {
+            let labels: Vec<String> = issue["labels"]["nodes"]
+                .as_array()
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|l| l["name"].as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let body = issue["body"].as_str().unwrap_or("");
+            let has_bounty_label = labels.iter().any(|l| {
+                l.to_lowercase().contains("bounty")
+                    || l.to_lowercase().contains("reward")
+                    || l.to_lowercase().contains("paid")
+            });
+
+            if !has_bounty_label && !is_reward_issue(body) {
+                continue;
+            }
+
+            let (reward, difficulty) = parse_reward_info(body);
 
-        if has_bounty_label || is_reward_issue(&issue["body"].as_str().unwrap_or("")) {
-            let (reward, difficulty) = parse_reward_info(&issue["body"].as_str().unwrap_or(""));
-            
             bounties.push(BountyLead {
                 number: issue["number"].as_u64().unwrap_or(0),
                 title: issue["title"].as_str().unwrap_or("").to_string(),
-                body: issue["body"].as_str().unwrap_or("").to_string(),
+                body: body.to_string(),
                 labels,
                 reward_estimate: reward,
                 difficulty,
-                url: issue["html_url"].as_str().unwrap_or("").to_string(),
+                url: issue["url"].as_str().unwrap_or("").to_string(),
                 repository: format!("{}/{}", owner, repo),
+                reactions: issue["reactions"]["totalCount"].as_u64().unwrap_or(0),
+                comments: issue["comments"]["totalCount"].as_u64().unwrap_or(0),
+                created_at: issue["createdAt"].as_str().unwrap_or("").to_string(),
             });
         }
+
+        let page_info = &issues["pageInfo"];
+        if page_info["hasNextPage"].as_bool().unwrap_or(false) {
+            cursor = page_info["endCursor"].as_str().map(String::from);
+        } else {
+            break;
+        }
     }
 
     // Sort by score (descending)
@@ -104,15 +226,15 @@ pub async fn scan_bounties(
 }
 
 fn is_reward_issue(body: &str) -> bool {
-    body.to_lowercase().contains("rtc") ||
-    body.to_lowercase().contains("reward") ||
-    body.to_lowercase().contains("bounty") ||
-    body.to_lowercase().contains("payment")
+    body.to_lowercase().contains("rtc")
+        || body.to_lowercase().contains("reward")
+        || body.to_lowercase().contains("bounty")
+        || body.to_lowercase().contains("payment")
 }
 
 fn parse_reward_info(body: &str) -> (String, String) {
     let body_lower = body.to_lowercase();
-    
+
     let reward = if body_lower.contains("100") {
         "100+ RTC".to_string()
     } else if body_lower.contains("50") {
@@ -139,19 +261,38 @@ fn parse_reward_info(body: &str) -> (String, String) {
 }
 
 pub async fn scan_multiple_repos(
-    repos: HashMap<&str, &str>,
+    repos: Vec<(&str, &str)>,
     github_token: &str,
+    transport: Arc<dyn Transport>,
 ) -> Result<Vec<BountyLead>> {
-    let mut all_bounties = Vec::new();
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_SCANS));
+    let mut handles = Vec::new();
 
     for (owner, repo) in repos {
-        match scan_bounties(owner, repo, github_token).await {
-            Ok(mut bounties) => {
-                all_bounties.append(&mut bounties);
-            }
-            Err(e) => {
+        let semaphore = semaphore.clone();
+        let transport = transport.clone();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let github_token = github_token.to_string();
+
+        handles.push(tokio::spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .expect("scan semaphore should never be closed");
+            let result = scan_bounties(&owner, &repo, &github_token, transport.as_ref()).await;
+            (owner, repo, result)
+        }));
+    }
+
+    let mut all_bounties = Vec::new();
+    for handle in handles {
+        match handle.await {
+            Ok((_owner, _repo, Ok(mut bounties))) => all_bounties.append(&mut bounties),
+            Ok((owner, repo, Err(e))) => {
                 eprintln!("Warning: Failed to scan {}/{}: {}", owner, repo, e);
             }
+            Err(e) => eprintln!("Warning: Scan task panicked: {}", e),
         }
     }
 
@@ -161,3 +302,28 @@ pub async fn scan_multiple_repos(
 
     Ok(all_bounties)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::ReplayTransport;
+
+    #[tokio::test]
+    async fn scan_bounties_follows_pagination_and_parses_leads() {
+        let transport = ReplayTransport::load(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/scan_bounties.json"
+        ))
+        .expect("failed to load fixture");
+
+        let bounties = scan_bounties("acme", "widget", "test-token", &transport)
+            .await
+            .expect("scan_bounties should succeed against the fixture");
+
+        assert_eq!(bounties.len(), 2);
+        assert!(bounties.iter().any(|b| b.number == 1 && b.reward_estimate == "100+ RTC"));
+        assert!(bounties.iter().any(|b| b.number == 2 && b.reward_estimate == "25 RTC"));
+        // Both pages were served, and the higher-reward, more-engaged issue ranks first.
+        assert_eq!(bounties[0].number, 1);
+    }
+}
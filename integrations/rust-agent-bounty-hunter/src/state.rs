@@ -0,0 +1,308 @@
+//! Bounty State Store - Persists bounty lifecycle across runs
+//!
+//! This module handles:
+//! - Recording each bounty as a row with its current lifecycle state
+//! - Transitioning bounties between states as the pipeline progresses
+//! - Answering "what did we already claim?" without re-scanning GitHub
+
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+use std::path::Path;
+use std::time::Duration;
+
+/// How long a writer waits on SQLite's lock before giving up. `ErrorChannel`
+/// opens its own connection to the same file and drains failures on its own
+/// task while the sweep keeps running, which means it writes concurrently with
+/// whatever opened this one; without a busy timeout the loser of that race
+/// gets `SQLITE_BUSY` immediately instead of just waiting its turn.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Where a bounty currently sits in the claim -> submit -> payout pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunState {
+    Discovered,
+    Claimed,
+    InProgress,
+    Submitted,
+    PaidOut,
+    Failed,
+}
+
+impl RunState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RunState::Discovered => "discovered",
+            RunState::Claimed => "claimed",
+            RunState::InProgress => "in_progress",
+            RunState::Submitted => "submitted",
+            RunState::PaidOut => "paid_out",
+            RunState::Failed => "failed",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "discovered" => Some(RunState::Discovered),
+            "claimed" => Some(RunState::Claimed),
+            "in_progress" => Some(RunState::InProgress),
+            "submitted" => Some(RunState::Submitted),
+            "paid_out" => Some(RunState::PaidOut),
+            "failed" => Some(RunState::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// A single bounty's row in the state store.
+#[derive(Debug, Clone)]
+pub struct BountyRecord {
+    pub issue_number: u64,
+    pub repository: String,
+    pub wallet: Option<String>,
+    pub github_handle: Option<String>,
+    pub pr_url: Option<String>,
+    pub state: RunState,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Live counts of bounties grouped by lifecycle state, used to drive
+/// progress reports instead of hand-counted integers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProgressCounts {
+    pub claimed: u64,
+    pub in_progress: u64,
+    pub submitted: u64,
+}
+
+/// A thin wrapper over a `rusqlite` connection holding the bounty lifecycle table.
+pub struct DbCtx {
+    conn: Connection,
+}
+
+impl DbCtx {
+    /// Opens (creating if needed) the state database at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let conn = Connection::open(path).context("Failed to open state database")?;
+        conn.busy_timeout(BUSY_TIMEOUT)
+            .context("Failed to set busy timeout on state database")?;
+        let ctx = DbCtx { conn };
+        ctx.init_schema()?;
+        Ok(ctx)
+    }
+
+    /// Opens an in-memory database, useful for tests and dry runs.
+    pub fn open_in_memory() -> Result<Self> {
+        let conn = Connection::open_in_memory().context("Failed to open in-memory state database")?;
+        let ctx = DbCtx { conn };
+        ctx.init_schema()?;
+        Ok(ctx)
+    }
+
+    fn init_schema(&self) -> Result<()> {
+        self.conn
+            .execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS bounties (
+                    repository    TEXT NOT NULL,
+                    issue_number  INTEGER NOT NULL,
+                    wallet        TEXT,
+                    github_handle TEXT,
+                    pr_url        TEXT,
+                    state         TEXT NOT NULL,
+                    created_at    INTEGER NOT NULL,
+                    updated_at    INTEGER NOT NULL,
+                    PRIMARY KEY (repository, issue_number)
+                );
+
+                CREATE TABLE IF NOT EXISTS auto_checkpoints (
+                    repository       TEXT PRIMARY KEY,
+                    last_issue_number INTEGER NOT NULL,
+                    status           TEXT NOT NULL,
+                    updated_at       INTEGER NOT NULL
+                );
+                "#,
+            )
+            .context("Failed to initialize state schema")
+    }
+
+    /// Inserts a newly discovered bounty if it isn't already tracked.
+    pub fn record_discovered(&self, repository: &str, issue_number: u64, now: i64) -> Result<()> {
+        self.conn
+            .execute(
+                "INSERT OR IGNORE INTO bounties
+                 (repository, issue_number, wallet, github_handle, pr_url, state, created_at, updated_at)
+                 VALUES (?1, ?2, NULL, NULL, NULL, ?3, ?4, ?4)",
+                params![repository, issue_number as i64, RunState::Discovered.as_str(), now],
+            )
+            .context("Failed to record discovered bounty")?;
+        Ok(())
+    }
+
+    /// Transitions a bounty to a new state, upserting wallet/handle/PR fields when provided.
+    #[allow(clippy::too_many_arguments)]
+    pub fn transition(
+        &self,
+        repository: &str,
+        issue_number: u64,
+        state: RunState,
+        wallet: Option<&str>,
+        github_handle: Option<&str>,
+        pr_url: Option<&str>,
+        now: i64,
+    ) -> Result<()> {
+        self.conn
+            .execute(
+                "INSERT INTO bounties
+                 (repository, issue_number, wallet, github_handle, pr_url, state, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)
+                 ON CONFLICT(repository, issue_number) DO UPDATE SET
+                    wallet = COALESCE(?3, wallet),
+                    github_handle = COALESCE(?4, github_handle),
+                    pr_url = COALESCE(?5, pr_url),
+                    state = ?6,
+                    updated_at = ?7",
+                params![
+                    repository,
+                    issue_number as i64,
+                    wallet,
+                    github_handle,
+                    pr_url,
+                    state.as_str(),
+                    now
+                ],
+            )
+            .context("Failed to transition bounty state")?;
+        Ok(())
+    }
+
+    /// Returns true if this bounty has already been claimed (or moved further along),
+    /// so callers can avoid double-claiming.
+    pub fn is_already_claimed(&self, repository: &str, issue_number: u64) -> Result<bool> {
+        let state: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT state FROM bounties WHERE repository = ?1 AND issue_number = ?2",
+                params![repository, issue_number as i64],
+                |row| row.get(0),
+            )
+            .ok();
+
+        Ok(matches!(
+            state.as_deref().and_then(RunState::from_str),
+            Some(RunState::Claimed)
+                | Some(RunState::InProgress)
+                | Some(RunState::Submitted)
+                | Some(RunState::PaidOut)
+        ))
+    }
+
+    /// Live counts of claimed / in-progress / submitted bounties for progress reporting.
+    pub fn progress_counts(&self) -> Result<ProgressCounts> {
+        let mut counts = ProgressCounts::default();
+        let mut stmt = self
+            .conn
+            .prepare("SELECT state, COUNT(*) FROM bounties GROUP BY state")
+            .context("Failed to prepare progress count query")?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                let state: String = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((state, count as u64))
+            })
+            .context("Failed to query progress counts")?;
+
+        for row in rows {
+            let (state, count) = row.context("Failed to read progress count row")?;
+            match RunState::from_str(&state) {
+                Some(RunState::Claimed) => counts.claimed += count,
+                Some(RunState::InProgress) => counts.in_progress += count,
+                Some(RunState::Submitted) | Some(RunState::PaidOut) => counts.submitted += count,
+                _ => {}
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Returns every tracked bounty, most recently updated first, for the `status` subcommand.
+    pub fn list_all(&self) -> Result<Vec<BountyRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT repository, issue_number, wallet, github_handle, pr_url, state, created_at, updated_at
+             FROM bounties ORDER BY updated_at DESC",
+        )?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                let state: String = row.get(5)?;
+                Ok(BountyRecord {
+                    repository: row.get(0)?,
+                    issue_number: row.get::<_, i64>(1)? as u64,
+                    wallet: row.get(2)?,
+                    github_handle: row.get(3)?,
+                    pr_url: row.get(4)?,
+                    state: RunState::from_str(&state).unwrap_or(RunState::Discovered),
+                    created_at: row.get(6)?,
+                    updated_at: row.get(7)?,
+                })
+            })
+            .context("Failed to query tracked bounties")?;
+
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to read tracked bounty rows")
+    }
+
+    /// Marks an `Auto` sweep over `repository` as running, starting (or resuming)
+    /// from `last_issue_number`.
+    pub fn start_auto_checkpoint(&self, repository: &str, last_issue_number: u64, now: i64) -> Result<()> {
+        self.conn
+            .execute(
+                "INSERT INTO auto_checkpoints (repository, last_issue_number, status, updated_at)
+                 VALUES (?1, ?2, 'running', ?3)
+                 ON CONFLICT(repository) DO UPDATE SET
+                    last_issue_number = ?2, status = 'running', updated_at = ?3",
+                params![repository, last_issue_number as i64, now],
+            )
+            .context("Failed to start auto checkpoint")?;
+        Ok(())
+    }
+
+    /// Records that `issue_number` was the last bounty fully processed in this sweep.
+    pub fn advance_auto_checkpoint(&self, repository: &str, issue_number: u64, now: i64) -> Result<()> {
+        self.conn
+            .execute(
+                "UPDATE auto_checkpoints SET last_issue_number = ?2, updated_at = ?3 WHERE repository = ?1",
+                params![repository, issue_number as i64, now],
+            )
+            .context("Failed to advance auto checkpoint")?;
+        Ok(())
+    }
+
+    /// Marks the sweep over `repository` as finished (idle), so a later invocation
+    /// starts a fresh run rather than treating it as a resume.
+    pub fn finish_auto_checkpoint(&self, repository: &str, now: i64) -> Result<()> {
+        self.conn
+            .execute(
+                "UPDATE auto_checkpoints SET status = 'idle', updated_at = ?2 WHERE repository = ?1",
+                params![repository, now],
+            )
+            .context("Failed to finish auto checkpoint")?;
+        Ok(())
+    }
+
+    /// Returns `(last_issue_number, is_running)` for a prior `Auto` sweep over
+    /// `repository`, if one was ever recorded.
+    pub fn load_auto_checkpoint(&self, repository: &str) -> Result<Option<(u64, bool)>> {
+        let row: Option<(i64, String)> = self
+            .conn
+            .query_row(
+                "SELECT last_issue_number, status FROM auto_checkpoints WHERE repository = ?1",
+                params![repository],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .ok();
+
+        Ok(row.map(|(last, status)| (last as u64, status == "running")))
+    }
+}
@@ -6,8 +6,13 @@
 //! - Updating issue status
 //! - Managing submission lifecycle
 
-use anyhow::{Result, Context};
+use crate::analyzer::BountyAnalysis;
+use crate::engine::RemoteGitEngine;
+use crate::http;
+use crate::vcs::{self, VcsAuth};
+use anyhow::{Context, Result};
 use serde_json::json;
+use std::path::Path;
 
 #[derive(Debug, Clone)]
 pub struct SubmitResult {
@@ -15,6 +20,17 @@ pub struct SubmitResult {
     pub action: String,
     pub url: Option<String>,
     pub message: String,
+    /// Seconds to wait before retrying, taken from the response's `Retry-After`
+    /// header when a request failed with a rate-limit or server error.
+    pub retry_after_secs: Option<u64>,
+}
+
+fn retry_after_secs(response: &reqwest::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
 }
 
 pub async fn create_pr(
@@ -26,13 +42,10 @@ pub async fn create_pr(
     base: &str,
     github_token: &str,
 ) -> Result<SubmitResult> {
-    let client = reqwest::Client::new();
-    
-    let url = format!(
-        "https://api.github.com/repos/{}/{}/pulls",
-        owner, repo
-    );
-    
+    let client = http::shared_client();
+
+    let url = format!("https://api.github.com/repos/{}/{}/pulls", owner, repo);
+
     let payload = json!({
         "title": title,
         "body": body,
@@ -40,241 +53,220 @@ pub async fn create_pr(
         "base": base
     });
 
-    let response = client
-        .post(&url)
-        .header("Authorization", format!("token {}", github_token))
-        .header("Accept", "application/vnd.github.v3+json")
-        .json(&payload)
-        .send()
-        .await
-        .context("Failed to create PR")?;
+    let response = http::send_with_retry(
+        client
+            .post(&url)
+            .header("Authorization", format!("token {}", github_token))
+            .header("Accept", "application/vnd.github.v3+json")
+            .json(&payload),
+    )
+    .await
+    .context("Failed to create PR")?;
 
     if response.status().is_success() {
-        let pr: serde_json::Value = response.json().await.context("Failed to parse PR response")?;
+        let pr: serde_json::Value = response
+            .json()
+            .await
+            .context("Failed to parse PR response")?;
         let pr_url = pr["html_url"].as_str().unwrap_or("").to_string();
-        
+
         Ok(SubmitResult {
             success: true,
             action: "PR Created".to_string(),
             url: Some(pr_url),
             message: format!("PR created successfully: {}", pr_url),
+            retry_after_secs: None,
         })
     } else {
+        let retry_after = retry_after_secs(&response);
         let error = response.text().await.context("Failed to get error")?;
         Ok(SubmitResult {
             success: false,
             action: "PR Creation Failed".to_string(),
             url: None,
             message: format!("Failed to create PR: {}", error),
+            retry_after_secs: retry_after,
         })
     }
 }
 
-pub async fn post_issue_comment(
-    owner: &str,
-    repo: &str,
-    issue_number: u64,
-    comment: &str,
-    github_token: &str,
-) -> Result<SubmitResult> {
-    let client = reqwest::Client::new();
-    
-    let url = format!(
-        "https://api.github.com/repos/{}/{}/issues/{}/comments",
-        owner, repo, issue_number
-    );
-    
-    let payload = json!({
-        "body": comment
-    });
-
-    let response = client
-        .post(&url)
-        .header("Authorization", format!("token {}", github_token))
-        .header("Accept", "application/vnd.github.v3+json")
-        .json(&payload)
-        .send()
-        .await
-        .context("Failed to post comment")?;
-
-    if response.status().is_success() {
-        let comment_response: serde_json::Value = response.json().await.context("Failed to parse comment response")?;
-        let comment_url = comment_response["html_url"].as_str().unwrap_or("").to_string();
-        
-        Ok(SubmitResult {
-            success: true,
-            action: "Comment Posted".to_string(),
-            url: Some(comment_url),
-            message: "Comment posted successfully".to_string(),
-        })
-    } else {
-        let error = response.text().await.context("Failed to get error")?;
-        Ok(SubmitResult {
-            success: false,
-            action: "Comment Failed".to_string(),
-            url: None,
-            message: format!("Failed to post comment: {}", error),
-        })
-    }
-}
-
-pub async fn update_issue(
+/// Clones `clone_url` into `work_dir`, creates a fix branch from the issue number,
+/// commits whatever is currently in the working tree (the implementation diff the
+/// caller has already written there), pushes it, and opens the PR through the
+/// GitHub API. Returns the resulting `SubmitResult` with the PR URL on success.
+pub async fn generate_and_submit_pr(
     owner: &str,
     repo: &str,
-    issue_number: u64,
-    labels: Option<Vec<&str>>,
-    state: Option<&str>,
+    clone_url: &str,
+    work_dir: &Path,
+    base_branch: &str,
+    analysis: &BountyAnalysis,
+    pr_body: &str,
+    auth: &VcsAuth,
     github_token: &str,
 ) -> Result<SubmitResult> {
-    let client = reqwest::Client::new();
-    
-    let url = format!(
-        "https://api.github.com/repos/{}/{}/issues/{}",
-        owner, repo, issue_number
-    );
-    
-    let mut payload = json!({});
-    
-    if let Some(labels) = labels {
-        payload["labels"] = json!(labels);
-    }
-    
-    if let Some(state) = state {
-        payload["state"] = json!(state);
-    }
+    let repository = vcs::clone_repo(clone_url, work_dir, auth)?;
 
-    let response = client
-        .patch(&url)
-        .header("Authorization", format!("token {}", github_token))
-        .header("Accept", "application/vnd.github.v3+json")
-        .json(&payload)
-        .send()
-        .await
-        .context("Failed to update issue")?;
+    let branch_name = vcs::fix_branch_name(analysis.number);
+    vcs::create_fix_branch(&repository, &branch_name)?;
+    vcs::commit_all(&repository, analysis)?;
+    vcs::push_branch(&repository, "origin", &branch_name, auth)?;
 
-    if response.status().is_success() {
-        Ok(SubmitResult {
-            success: true,
-            action: "Issue Updated".to_string(),
-            url: None,
-            message: "Issue updated successfully".to_string(),
-        })
-    } else {
-        let error = response.text().await.context("Failed to get error")?;
-        Ok(SubmitResult {
-            success: false,
-            action: "Update Failed".to_string(),
-            url: None,
-            message: format!("Failed to update issue: {}", error),
-        })
-    }
-}
-
-pub async fn submit_pr_review(
-    owner: &str,
-    repo: &str,
-    pr_number: u64,
-    event: &str,
-    body: &str,
-    github_token: &str,
-) -> Result<SubmitResult> {
-    let client = reqwest::Client::new();
-    
-    let url = format!(
-        "https://api.github.com/repos/{}/{}/pulls/{}/reviews",
-        owner, repo, pr_number
-    );
-    
-    let payload = json!({
-        "event": event, // APPROVE, REQUEST_CHANGES, COMMENT
-        "body": body
-    });
-
-    let response = client
-        .post(&url)
-        .header("Authorization", format!("token {}", github_token))
-        .header("Accept", "application/vnd.github.v3+json")
-        .json(&payload)
-        .send()
-        .await
-        .context("Failed to submit review")?;
-
-    if response.status().is_success() {
-        Ok(SubmitResult {
-            success: true,
-            action: "Review Submitted".to_string(),
-            url: None,
-            message: format!("Review submitted: {}", event),
-        })
-    } else {
-        let error = response.text().await.context("Failed to get error")?;
-        Ok(SubmitResult {
-            success: false,
-            action: "Review Failed".to_string(),
-            url: None,
-            message: format!("Failed to submit review: {}", error),
-        })
-    }
+    let title = format!("Fix #{}: {}", analysis.number, analysis.title);
+    create_pr(
+        owner,
+        repo,
+        &title,
+        pr_body,
+        &branch_name,
+        base_branch,
+        github_token,
+    )
+    .await
 }
 
+/// Posts the claim comment and applies the claimed label through whichever
+/// `RemoteGitEngine` the caller is pointed at (GitHub, Gitea, or GitLab).
 pub async fn claim_bounty(
+    engine: &dyn RemoteGitEngine,
     owner: &str,
     repo: &str,
     issue_number: u64,
     claim_comment: &str,
     add_label: &str,
-    github_token: &str,
 ) -> Result<SubmitResult> {
     // Post claim comment
-    let comment_result = post_issue_comment(owner, repo, issue_number, claim_comment, github_token).await?;
-    
+    let comment_result = engine
+        .post_comment(owner, repo, issue_number, claim_comment)
+        .await?;
+
     if !comment_result.success {
         return Ok(comment_result);
     }
-    
+
     // Add claim label
-    let label_result = update_issue(
-        owner, repo, issue_number,
-        Some(vec![add_label, "claimed"]),
-        None,
-        github_token
-    ).await?;
-    
+    let label_result = engine
+        .update_issue_labels_state(
+            owner,
+            repo,
+            issue_number,
+            Some(vec![add_label, "claimed"]),
+            None,
+        )
+        .await?;
+
     Ok(SubmitResult {
         success: label_result.success,
         action: "Bounty Claimed".to_string(),
         url: comment_result.url,
-        message: format!("Bounty claimed. Comment: {}, Label: {}", comment_result.message, label_result.message),
+        message: format!(
+            "Bounty claimed. Comment: {}, Label: {}",
+            comment_result.message, label_result.message
+        ),
+        retry_after_secs: label_result.retry_after_secs,
     })
 }
 
+/// Posts the submission comment and marks the issue under review through
+/// whichever `RemoteGitEngine` the caller is pointed at.
 pub async fn submit_bounty_completion(
+    engine: &dyn RemoteGitEngine,
     owner: &str,
     repo: &str,
     issue_number: u64,
     pr_url: &str,
     submission_comment: &str,
-    github_token: &str,
 ) -> Result<SubmitResult> {
     // Post submission comment
-    let comment_result = post_issue_comment(owner, repo, issue_number, submission_comment, github_token).await?;
-    
+    let comment_result = engine
+        .post_comment(owner, repo, issue_number, submission_comment)
+        .await?;
+
     if !comment_result.success {
         return Ok(comment_result);
     }
-    
+
     // Update issue to "submitted" status
-    let update_result = update_issue(
-        owner, repo, issue_number,
-        Some(vec!["submitted", "under-review"]),
-        None,
-        github_token
-    ).await?;
-    
+    let update_result = engine
+        .update_issue_labels_state(
+            owner,
+            repo,
+            issue_number,
+            Some(vec!["submitted", "under-review"]),
+            None,
+        )
+        .await?;
+
     Ok(SubmitResult {
         success: update_result.success,
         action: "Submission Complete".to_string(),
         url: Some(pr_url.to_string()),
         message: format!("Submission complete. PR: {}, Status updated", pr_url),
+        retry_after_secs: update_result.retry_after_secs,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::GitHubClient;
+    use crate::transport::ReplayTransport;
+    use std::sync::Arc;
+
+    fn github_replaying(fixture: &str) -> GitHubClient {
+        let transport = ReplayTransport::load(fixture).expect("failed to load fixture");
+        GitHubClient::with_transport(Arc::new(transport), "test-token")
+    }
+
+    #[tokio::test]
+    async fn claim_bounty_posts_comment_and_label() {
+        let github = github_replaying(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/claim_bounty.json"
+        ));
+
+        let result = claim_bounty(
+            &github,
+            "acme",
+            "widget",
+            42,
+            "Claiming this bounty!",
+            "claimed",
+        )
+        .await
+        .expect("claim_bounty should succeed against the fixture");
+
+        assert!(result.success);
+        assert_eq!(result.action, "Bounty Claimed");
+        assert_eq!(
+            result.url.as_deref(),
+            Some("https://github.com/acme/widget/issues/42#issuecomment-1")
+        );
+    }
+
+    #[tokio::test]
+    async fn submit_bounty_completion_posts_comment_and_updates_labels() {
+        let github = github_replaying(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/submit_bounty_completion.json"
+        ));
+
+        let result = submit_bounty_completion(
+            &github,
+            "acme",
+            "widget",
+            42,
+            "https://github.com/acme/widget/pull/99",
+            "Submission ready for review!",
+        )
+        .await
+        .expect("submit_bounty_completion should succeed against the fixture");
+
+        assert!(result.success);
+        assert_eq!(result.action, "Submission Complete");
+        assert_eq!(
+            result.url.as_deref(),
+            Some("https://github.com/acme/widget/pull/99")
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Toolchain Runner - Actually builds, lints, and tests a PR's branch
+//!
+//! This module handles:
+//! - Cloning a PR's head repo/branch into a scratch directory via git2
+//! - Running `cargo fmt -- --check`, `cargo clippy --message-format=json`,
+//!   and `cargo test` as subprocesses against that checkout
+//! - Parsing their output into `QualityCheck` entries with genuinely earned scores
+
+use crate::quality::{QualityCheck, QualityReport};
+use crate::vcs::{self, VcsAuth};
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+/// Clones `clone_url` at `branch` into a scratch directory and runs the full
+/// fmt/clippy/test suite against it, returning a `QualityReport` built from
+/// real toolchain output rather than filename heuristics.
+pub fn run_toolchain_checks(
+    clone_url: &str,
+    branch: &str,
+    auth: &VcsAuth,
+) -> Result<QualityReport> {
+    let work_dir = std::env::temp_dir().join(format!("bounty-quality-{}", std::process::id()));
+    if work_dir.exists() {
+        std::fs::remove_dir_all(&work_dir)
+            .with_context(|| format!("Failed to clear stale scratch dir {}", work_dir.display()))?;
+    }
+
+    vcs::clone_repo_branch(clone_url, &work_dir, branch, auth)
+        .context("Failed to clone PR branch for quality checks")?;
+
+    let report = run_checks_in_dir(&work_dir);
+    std::fs::remove_dir_all(&work_dir).ok();
+    report
+}
+
+/// Runs the same fmt/clippy/test suite against an already-checked-out directory.
+pub fn run_checks_in_dir(dir: &Path) -> Result<QualityReport> {
+    let mut checks = Vec::new();
+    let mut total_score = 0u64;
+    let mut max_score = 0u64;
+
+    for check in [
+        run_fmt_check(dir)?,
+        run_clippy_check(dir)?,
+        run_test_check(dir)?,
+    ] {
+        total_score += check.score;
+        max_score += check.max_score;
+        checks.push(check);
+    }
+
+    Ok(QualityReport {
+        passed: total_score >= max_score / 2,
+        score: total_score,
+        max_score,
+        checks,
+    })
+}
+
+fn run_fmt_check(dir: &Path) -> Result<QualityCheck> {
+    let output = Command::new("cargo")
+        .args(["fmt", "--", "--check"])
+        .current_dir(dir)
+        .output()
+        .context("Failed to run cargo fmt")?;
+
+    let clean = output.status.success();
+    Ok(QualityCheck {
+        name: "Formatting".to_string(),
+        passed: clean,
+        score: if clean { 15 } else { 0 },
+        max_score: 15,
+        message: if clean {
+            "cargo fmt -- --check passed".to_string()
+        } else {
+            format!(
+                "⚠️ cargo fmt -- --check found unformatted files:\n{}",
+                String::from_utf8_lossy(&output.stdout)
+            )
+        },
+    })
+}
+
+fn run_clippy_check(dir: &Path) -> Result<QualityCheck> {
+    let output = Command::new("cargo")
+        .args(["clippy", "--message-format=json"])
+        .current_dir(dir)
+        .output()
+        .context("Failed to run cargo clippy")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut warnings = 0u64;
+    let mut errors = 0u64;
+
+    for line in stdout.lines() {
+        let Ok(diagnostic) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        match diagnostic["message"]["level"].as_str() {
+            Some("warning") => warnings += 1,
+            Some("error") => errors += 1,
+            _ => {}
+        }
+    }
+
+    let clean = warnings == 0 && errors == 0;
+    let score = if errors > 0 {
+        0
+    } else {
+        25u64
+            .saturating_sub(warnings * 2)
+            .max(if warnings == 0 { 25 } else { 5 })
+    };
+
+    Ok(QualityCheck {
+        name: "Clippy".to_string(),
+        passed: clean,
+        score,
+        max_score: 25,
+        message: if clean {
+            "cargo clippy reported zero warnings".to_string()
+        } else {
+            format!("⚠️ cargo clippy found {warnings} warning(s) and {errors} error(s)")
+        },
+    })
+}
+
+fn run_test_check(dir: &Path) -> Result<QualityCheck> {
+    let output = Command::new("cargo")
+        .args(["test"])
+        .current_dir(dir)
+        .output()
+        .context("Failed to run cargo test")?;
+
+    let (passed, failed) = parse_test_summary(&String::from_utf8_lossy(&output.stdout));
+    let all_passed = output.status.success() && failed == 0;
+
+    Ok(QualityCheck {
+        name: "Tests".to_string(),
+        passed: all_passed,
+        score: if all_passed { 30 } else { 0 },
+        max_score: 30,
+        message: if all_passed {
+            format!("{passed} test(s) passed")
+        } else {
+            format!("⚠️ {failed} test(s) failed, {passed} passed")
+        },
+    })
+}
+
+/// Sums the `N passed; M failed` counts out of every `test result: ...` summary
+/// line `cargo test` prints (one per test binary it runs).
+fn parse_test_summary(stdout: &str) -> (u64, u64) {
+    let mut passed = 0u64;
+    let mut failed = 0u64;
+
+    for line in stdout.lines() {
+        if !line.trim_start().starts_with("test result:") {
+            continue;
+        }
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        for (i, word) in words.iter().enumerate() {
+            if i == 0 {
+                continue;
+            }
+            let count = words[i - 1].trim_end_matches(|c: char| !c.is_ascii_digit());
+            if word.starts_with("passed") {
+                passed += count.parse::<u64>().unwrap_or(0);
+            } else if word.starts_with("failed") {
+                failed += count.parse::<u64>().unwrap_or(0);
+            }
+        }
+    }
+
+    (passed, failed)
+}
@@ -0,0 +1,299 @@
+//! HTTP Transport - The injectable seam between forge clients and the network
+//!
+//! This module handles:
+//! - A `Transport` trait that `GitHubClient`, `scan_bounties`, and
+//!   `validate_submission` send requests through instead of calling
+//!   `reqwest` directly
+//! - `ReqwestTransport`, the real implementation used in production
+//! - `RecordingTransport` / `ReplayTransport`, which capture or serve
+//!   versioned JSON fixtures so the GitHub-facing code can be tested offline
+
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Current fixture file format; bump when `Exchange`'s shape changes so a
+/// stale fixture fails loudly instead of replaying garbage.
+pub const FIXTURE_VERSION: u32 = 1;
+
+/// A request as built by a forge client, independent of `reqwest` so it can
+/// be replayed from a fixture without ever touching the network.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+    pub fn new(method: &str, url: impl Into<String>) -> Self {
+        HttpRequest {
+            method: method.to_string(),
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_string(), value.into()));
+        self
+    }
+
+    pub fn json(mut self, value: &impl Serialize) -> Result<Self> {
+        self.body = Some(serde_json::to_vec(value).context("Failed to serialize request body")?);
+        self.headers
+            .push(("Content-Type".to_string(), "application/json".to_string()));
+        Ok(self)
+    }
+
+    /// The path component of `url`, which is what fixtures match on so a
+    /// recording made against one host can replay against another.
+    pub fn path(&self) -> String {
+        reqwest::Url::parse(&self.url)
+            .map(|u| u.path().to_string())
+            .unwrap_or_else(|_| self.url.clone())
+    }
+}
+
+/// A response as handed back to a forge client, independent of `reqwest` for
+/// the same reason `HttpRequest` is.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Seconds to wait before retrying, taken from a `Retry-After` header.
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        self.header("retry-after").and_then(|v| v.parse().ok())
+    }
+
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_slice(&self.body).context("Failed to parse response body as JSON")
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).to_string()
+    }
+}
+
+/// The seam forge clients send requests through. Production code runs on
+/// `ReqwestTransport`; tests swap in `ReplayTransport` to serve fixtures
+/// instead of hitting the network.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+/// Sends requests over the network via a shared `reqwest::Client`.
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let method = reqwest::Method::from_bytes(request.method.as_bytes())
+            .context("Invalid HTTP method")?;
+        let mut builder = self.client.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().await.context("Request failed to send")?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
+            .collect();
+        let body = response
+            .bytes()
+            .await
+            .context("Failed to read response body")?
+            .to_vec();
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// One recorded request/response pair, matched on method + path during replay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Exchange {
+    pub method: String,
+    pub path: String,
+    pub request_body: Option<String>,
+    pub status: u16,
+    pub response_body: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FixtureFile {
+    version: u32,
+    exchanges: Vec<Exchange>,
+}
+
+/// Wraps a real transport and appends every request/response pair it sees,
+/// writing them out as a versioned fixture once `save()` is called.
+pub struct RecordingTransport {
+    inner: ReqwestTransport,
+    path: PathBuf,
+    exchanges: Mutex<Vec<Exchange>>,
+}
+
+impl RecordingTransport {
+    pub fn new(client: reqwest::Client, path: impl Into<PathBuf>) -> Self {
+        RecordingTransport {
+            inner: ReqwestTransport::new(client),
+            path: path.into(),
+            exchanges: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Writes every exchange recorded so far to the fixture file, overwriting it.
+    pub fn save(&self) -> Result<()> {
+        let exchanges = self
+            .exchanges
+            .lock()
+            .expect("exchange log poisoned")
+            .clone();
+        let file = FixtureFile {
+            version: FIXTURE_VERSION,
+            exchanges,
+        };
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create fixture directory")?;
+        }
+        let json =
+            serde_json::to_string_pretty(&file).context("Failed to serialize fixture file")?;
+        std::fs::write(&self.path, json)
+            .with_context(|| format!("Failed to write fixture file {}", self.path.display()))
+    }
+}
+
+#[async_trait]
+impl Transport for RecordingTransport {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let path = request.path();
+        let method = request.method.clone();
+        let request_body = request
+            .body
+            .clone()
+            .map(|b| String::from_utf8_lossy(&b).to_string());
+
+        let response = self.inner.execute(request).await?;
+
+        self.exchanges
+            .lock()
+            .expect("exchange log poisoned")
+            .push(Exchange {
+                method,
+                path,
+                request_body,
+                status: response.status,
+                response_body: response.text(),
+            });
+
+        Ok(response)
+    }
+}
+
+/// Serves fixture exchanges recorded earlier, matching requests by method and
+/// path in the order they were recorded. An unmatched or out-of-order request
+/// fails with an error naming the mismatch, rather than silently returning
+/// something misleading.
+pub struct ReplayTransport {
+    remaining: Mutex<VecDeque<Exchange>>,
+}
+
+impl ReplayTransport {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let raw = std::fs::read_to_string(path.as_ref()).with_context(|| {
+            format!("Failed to read fixture file {}", path.as_ref().display())
+        })?;
+        let file: FixtureFile =
+            serde_json::from_str(&raw).context("Failed to parse fixture file")?;
+        if file.version != FIXTURE_VERSION {
+            bail!(
+                "Fixture file {} is version {}, expected {}",
+                path.as_ref().display(),
+                file.version,
+                FIXTURE_VERSION
+            );
+        }
+        Ok(ReplayTransport {
+            remaining: Mutex::new(file.exchanges.into()),
+        })
+    }
+
+    pub fn from_exchanges(exchanges: Vec<Exchange>) -> Self {
+        ReplayTransport {
+            remaining: Mutex::new(exchanges.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for ReplayTransport {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let path = request.path();
+        let mut remaining = self.remaining.lock().expect("fixture queue poisoned");
+        let exchange = remaining.pop_front().with_context(|| {
+            format!(
+                "Unexpected request with no fixture left to serve: {} {}",
+                request.method, path
+            )
+        })?;
+
+        if exchange.method != request.method || exchange.path != path {
+            bail!(
+                "Fixture mismatch: expected {} {}, got {} {}",
+                exchange.method,
+                exchange.path,
+                request.method,
+                path
+            );
+        }
+
+        Ok(HttpResponse {
+            status: exchange.status,
+            headers: Vec::new(),
+            body: exchange.response_body.into_bytes(),
+        })
+    }
+}
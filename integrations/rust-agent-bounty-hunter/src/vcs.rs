@@ -0,0 +1,189 @@
+//! Version Control - Clones repositories, branches, commits, and pushes via git2
+//!
+//! This module handles:
+//! - Authenticated clones over SSH or HTTPS
+//! - Creating a fix branch named from the bounty issue number
+//! - Staging/committing an implementation diff with a derived message
+//! - Pushing with progress reporting
+
+use crate::analyzer::BountyAnalysis;
+use anyhow::{Context, Result};
+use git2::{Cred, FetchOptions, PushOptions, RemoteCallbacks, Repository, Signature};
+use std::path::{Path, PathBuf};
+
+/// Credentials to use when talking to the remote.
+pub enum VcsAuth {
+    /// SSH key pair, defaulting to `~/.ssh/id_rsa` / `id_rsa.pub`.
+    SshKey {
+        private_key: PathBuf,
+        public_key: PathBuf,
+        passphrase: Option<String>,
+    },
+    /// HTTPS personal access token, sent as the password with the username in `user`.
+    HttpsToken { user: String, token: String },
+}
+
+impl VcsAuth {
+    /// Looks up the default SSH key pair under `~/.ssh`.
+    pub fn default_ssh_key() -> Result<Self> {
+        let home = dirs_home().context("Could not determine home directory")?;
+        Ok(VcsAuth::SshKey {
+            private_key: home.join(".ssh/id_rsa"),
+            public_key: home.join(".ssh/id_rsa.pub"),
+            passphrase: None,
+        })
+    }
+
+    fn callbacks(&self) -> RemoteCallbacks<'_> {
+        let mut callbacks = RemoteCallbacks::new();
+        match self {
+            VcsAuth::SshKey {
+                private_key,
+                public_key,
+                passphrase,
+            } => {
+                let private_key = private_key.clone();
+                let public_key = public_key.clone();
+                let passphrase = passphrase.clone();
+                callbacks.credentials(move |_url, username_from_url, _allowed_types| {
+                    Cred::ssh_key(
+                        username_from_url.unwrap_or("git"),
+                        Some(&public_key),
+                        &private_key,
+                        passphrase.as_deref(),
+                    )
+                });
+            }
+            VcsAuth::HttpsToken { user, token } => {
+                let user = user.clone();
+                let token = token.clone();
+                callbacks.credentials(move |_url, _username_from_url, _allowed_types| {
+                    Cred::userpass_plaintext(&user, &token)
+                });
+            }
+        }
+        callbacks
+    }
+}
+
+fn dirs_home() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(PathBuf::from)
+}
+
+/// Clones `clone_url` into `dest`, returning the opened repository.
+pub fn clone_repo(clone_url: &str, dest: impl AsRef<Path>, auth: &VcsAuth) -> Result<Repository> {
+    let mut fetch_options = FetchOptions::new();
+    fetch_options.remote_callbacks(auth.callbacks());
+
+    let mut builder = git2::build::RepoBuilder::new();
+    builder.fetch_options(fetch_options);
+
+    builder.clone(clone_url, dest.as_ref()).with_context(|| {
+        format!(
+            "Failed to clone {clone_url} into {}",
+            dest.as_ref().display()
+        )
+    })
+}
+
+/// Clones `clone_url` into `dest` with `branch` checked out, returning the opened repository.
+pub fn clone_repo_branch(
+    clone_url: &str,
+    dest: impl AsRef<Path>,
+    branch: &str,
+    auth: &VcsAuth,
+) -> Result<Repository> {
+    let mut fetch_options = FetchOptions::new();
+    fetch_options.remote_callbacks(auth.callbacks());
+
+    let mut builder = git2::build::RepoBuilder::new();
+    builder.fetch_options(fetch_options);
+    builder.branch(branch);
+
+    builder.clone(clone_url, dest.as_ref()).with_context(|| {
+        format!(
+            "Failed to clone {clone_url} (branch {branch}) into {}",
+            dest.as_ref().display()
+        )
+    })
+}
+
+/// Names a fix branch from the bounty's issue number, e.g. `bounty-fix/123`.
+pub fn fix_branch_name(issue_number: u64) -> String {
+    format!("bounty-fix/{issue_number}")
+}
+
+/// Creates (or resets) `branch_name` from the repository's current HEAD and checks it out.
+pub fn create_fix_branch(repo: &Repository, branch_name: &str) -> Result<()> {
+    let head_commit = repo.head()?.peel_to_commit()?;
+    repo.branch(branch_name, &head_commit, true)
+        .context("Failed to create fix branch")?;
+
+    let refname = format!("refs/heads/{branch_name}");
+    repo.set_head(&refname)
+        .with_context(|| format!("Failed to check out branch {branch_name}"))?;
+    repo.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))
+        .context("Failed to checkout fix branch")?;
+
+    Ok(())
+}
+
+/// Stages all changes in the working tree and commits them with a message derived
+/// from the bounty analysis.
+pub fn commit_all(repo: &Repository, analysis: &BountyAnalysis) -> Result<git2::Oid> {
+    let mut index = repo.index()?;
+    index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)?;
+    index.write()?;
+
+    let tree_oid = index.write_tree()?;
+    let tree = repo.find_tree(tree_oid)?;
+    let parent = repo.head()?.peel_to_commit()?;
+
+    let signature = Signature::now("Bounty Hunter Agent", "bounty-hunter@rustchain.local")
+        .context("Failed to build commit signature")?;
+
+    let message = format!(
+        "Fix #{}: {}\n\nComplexity: {}\nEstimated effort: {}",
+        analysis.number,
+        analysis.title,
+        analysis.technical_complexity.as_str(),
+        analysis.estimated_effort
+    );
+
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        &message,
+        &tree,
+        &[&parent],
+    )
+    .context("Failed to create commit")
+}
+
+/// Pushes `branch_name` to `remote_name`, reporting progress to stdout.
+pub fn push_branch(
+    repo: &Repository,
+    remote_name: &str,
+    branch_name: &str,
+    auth: &VcsAuth,
+) -> Result<()> {
+    let mut remote = repo
+        .find_remote(remote_name)
+        .with_context(|| format!("Remote {remote_name} not found"))?;
+
+    let mut callbacks = auth.callbacks();
+    callbacks.push_transfer_progress(|current, total, _bytes| {
+        if total > 0 {
+            println!("   Pushing objects: {current}/{total}");
+        }
+    });
+
+    let mut push_options = PushOptions::new();
+    push_options.remote_callbacks(callbacks);
+
+    let refspec = format!("refs/heads/{branch_name}:refs/heads/{branch_name}");
+    remote
+        .push(&[&refspec], Some(&mut push_options))
+        .with_context(|| format!("Failed to push branch {branch_name}"))
+}
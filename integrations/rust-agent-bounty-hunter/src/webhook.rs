@@ -0,0 +1,341 @@
+//! Webhook Daemon - Reacts to GitHub/Gitea events instead of requiring polling
+//!
+//! This module handles:
+//! - Serving an HTTP endpoint for GitHub/Gitea webhook deliveries
+//! - Verifying payload authenticity via HMAC-SHA256 against a configurable
+//!   list of `(secret, allowed_sender)` pairs, so multiple repos can share
+//!   one endpoint
+//! - Dispatching `issues` and `pull_request` events into the existing
+//!   analyze/claim/quality pipeline, and logging `push` events
+
+use crate::analyzer::analyze_bounty;
+use crate::audit::AuditStore;
+use crate::corpus::BountyCorpus;
+use crate::engine::GitHubClient;
+use crate::generator::generate_claim_comment;
+use crate::http;
+use crate::quality::validate_submission;
+use crate::state::{DbCtx, RunState};
+use crate::submitter::claim_bounty;
+use crate::transport::Transport;
+use anyhow::{Context, Result};
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+    Router,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// One `(secret, allowed_sender)` pair the webhook endpoint will accept
+/// deliveries for. `allowed_sender` is checked against the payload's
+/// `sender.login` when present, letting several repos or teams share a
+/// single endpoint with distinct secrets.
+#[derive(Debug, Clone)]
+pub struct WebhookSource {
+    pub secret: String,
+    pub allowed_sender: Option<String>,
+}
+
+/// Configuration for the webhook listener.
+#[derive(Debug, Clone)]
+pub struct ServeConfig {
+    /// Pre-shared secrets configured on the GitHub/Gitea webhooks, used to verify
+    /// `X-Hub-Signature-256`. Tried in order; the first one that matches wins.
+    pub sources: Vec<WebhookSource>,
+    /// Repositories (owner/repo) this daemon should act on; other repos are ignored.
+    pub watched_repos: Vec<String>,
+    /// Wallet address to use when auto-claiming a bounty.
+    pub wallet: String,
+    /// GitHub handle to use when auto-claiming a bounty.
+    pub handle: String,
+    /// If false, matching issue events are only analyzed and logged; no claim is posted.
+    pub auto_claim: bool,
+}
+
+struct ServeState {
+    config: ServeConfig,
+    token: String,
+    // `rusqlite::Connection` is `Send` but not `Sync`, and this state is shared
+    // across concurrent handlers behind an `Arc`, so every access is serialized
+    // through this lock rather than handed out bare.
+    db: Mutex<DbCtx>,
+    transport: Arc<dyn Transport>,
+    corpus: BountyCorpus,
+    audits: AuditStore,
+}
+
+/// Starts the webhook server and blocks until it is shut down.
+pub async fn serve(
+    config: ServeConfig,
+    token: String,
+    db: DbCtx,
+    corpus: BountyCorpus,
+    audits: AuditStore,
+    addr: &str,
+) -> Result<()> {
+    let state = Arc::new(ServeState {
+        config,
+        token,
+        db: Mutex::new(db),
+        transport: http::default_transport(),
+        corpus,
+        audits,
+    });
+
+    let app = Router::new()
+        .route("/webhook", post(handle_webhook))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind webhook listener on {addr}"))?;
+
+    println!("🛰️  Webhook daemon listening on {addr}");
+    axum::serve(listener, app)
+        .await
+        .context("Webhook server exited with an error")
+}
+
+async fn handle_webhook(
+    State(state): State<Arc<ServeState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, &'static str) {
+    let signature = headers
+        .get("X-Hub-Signature-256")
+        .and_then(|v| v.to_str().ok());
+
+    let Some(signature) = signature else {
+        return (StatusCode::UNAUTHORIZED, "missing signature");
+    };
+
+    let event = headers
+        .get("X-GitHub-Event")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("");
+
+    let payload: serde_json::Value = match serde_json::from_slice(&body) {
+        Ok(v) => v,
+        Err(_) => return (StatusCode::BAD_REQUEST, "invalid JSON payload"),
+    };
+
+    if !verify_signature(&state.config.sources, &body, signature, &payload) {
+        return (StatusCode::UNAUTHORIZED, "signature mismatch");
+    }
+
+    match event {
+        "issues" => dispatch_issue_event(&state, &payload).await,
+        "pull_request" => dispatch_pull_request_event(&state, &payload).await,
+        "push" => dispatch_push_event(&state, &payload),
+        "ping" => {}
+        _ => {}
+    }
+
+    (StatusCode::OK, "ok")
+}
+
+/// Computes `HMAC-SHA256(secret, body)` for each configured source, hex-encodes it,
+/// prefixes with `sha256=`, and compares against the supplied header in constant
+/// time. A source whose `allowed_sender` doesn't match `payload.sender.login` is
+/// skipped even if its secret matches, so repos can't spoof each other's sender.
+fn verify_signature(
+    sources: &[WebhookSource],
+    body: &[u8],
+    header_value: &str,
+    payload: &serde_json::Value,
+) -> bool {
+    let Some(expected_hex) = header_value.strip_prefix("sha256=") else {
+        return false;
+    };
+    let sender = payload["sender"]["login"].as_str();
+
+    sources.iter().any(|source| {
+        if let Some(allowed) = &source.allowed_sender {
+            if sender != Some(allowed.as_str()) {
+                return false;
+            }
+        }
+
+        let Ok(mut mac) = HmacSha256::new_from_slice(source.secret.as_bytes()) else {
+            return false;
+        };
+        mac.update(body);
+        let computed = mac.finalize().into_bytes();
+        let computed_hex = hex_encode(&computed);
+
+        constant_time_eq(computed_hex.as_bytes(), expected_hex.as_bytes())
+    })
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+async fn dispatch_issue_event(state: &ServeState, payload: &serde_json::Value) {
+    let action = payload["action"].as_str().unwrap_or("");
+    if action != "opened" && action != "labeled" {
+        return;
+    }
+
+    let repo_full = payload["repository"]["full_name"].as_str().unwrap_or("");
+    if !state.config.watched_repos.is_empty()
+        && !state.config.watched_repos.iter().any(|r| r == repo_full)
+    {
+        return;
+    }
+
+    if action == "labeled" {
+        let label = payload["label"]["name"].as_str().unwrap_or("");
+        if !label.to_lowercase().contains("bounty") {
+            return;
+        }
+    }
+
+    let issue_number = payload["issue"]["number"].as_u64().unwrap_or(0);
+    let title = payload["issue"]["title"].as_str().unwrap_or("");
+    let body = payload["issue"]["body"].as_str().unwrap_or("");
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    if let Err(e) = state.db.lock().await.record_discovered(repo_full, issue_number, now) {
+        eprintln!("Warning: failed to record discovered bounty #{issue_number}: {e}");
+        return;
+    }
+
+    let analysis = analyze_bounty(
+        issue_number,
+        title,
+        body,
+        Some(&state.corpus),
+        Some(&state.audits),
+    );
+    println!(
+        "📬 Webhook event: issue #{issue_number} in {repo_full} ({})",
+        analysis.technical_complexity.as_str()
+    );
+
+    if !state.config.auto_claim {
+        return;
+    }
+
+    let parts: Vec<&str> = repo_full.splitn(2, '/').collect();
+    let [owner, repo] = parts[..] else {
+        return;
+    };
+
+    let claim = generate_claim_comment(
+        issue_number,
+        repo_full,
+        &state.config.wallet,
+        &state.config.handle,
+        &analysis,
+    );
+    let github = GitHubClient::new(state.token.clone());
+    match claim_bounty(&github, owner, repo, issue_number, &claim.body, "claimed").await {
+        Ok(result) => {
+            let run_state = if result.success {
+                RunState::Claimed
+            } else {
+                RunState::Failed
+            };
+            if let Err(e) = state.db.lock().await.transition(
+                repo_full,
+                issue_number,
+                run_state,
+                Some(&state.config.wallet),
+                Some(&state.config.handle),
+                None,
+                now,
+            ) {
+                eprintln!("Warning: failed to record claim transition: {e}");
+            }
+            println!("   Auto-claim: {}", result.message);
+        }
+        Err(e) => eprintln!("Warning: auto-claim failed for #{issue_number}: {e}"),
+    }
+}
+
+/// When a PR opens with a head branch matching `vcs::fix_branch_name`'s
+/// `bounty-fix/{issue_number}` convention and that issue is already claimed,
+/// runs `validate_submission` so the quality report lands before a human
+/// ever looks at the diff.
+async fn dispatch_pull_request_event(state: &ServeState, payload: &serde_json::Value) {
+    let action = payload["action"].as_str().unwrap_or("");
+    if action != "opened" {
+        return;
+    }
+
+    let repo_full = payload["repository"]["full_name"].as_str().unwrap_or("");
+    if !state.config.watched_repos.is_empty()
+        && !state.config.watched_repos.iter().any(|r| r == repo_full)
+    {
+        return;
+    }
+
+    let pr_number = payload["pull_request"]["number"].as_u64().unwrap_or(0);
+    let head_ref = payload["pull_request"]["head"]["ref"]
+        .as_str()
+        .unwrap_or("");
+
+    let Some(issue_number) = head_ref
+        .strip_prefix("bounty-fix/")
+        .and_then(|n| n.parse::<u64>().ok())
+    else {
+        return;
+    };
+
+    match state.db.lock().await.is_already_claimed(repo_full, issue_number) {
+        Ok(true) => {}
+        Ok(false) => return,
+        Err(e) => {
+            eprintln!("Warning: failed to check claim status for #{issue_number}: {e}");
+            return;
+        }
+    }
+
+    println!(
+        "📬 Webhook event: PR #{pr_number} opened against claimed bounty #{issue_number} in {repo_full}, running quality checks"
+    );
+
+    match validate_submission(pr_number, repo_full, &state.token, state.transport.as_ref()).await {
+        Ok(report) => println!("   Quality check: {}", report.summary()),
+        Err(e) => eprintln!("Warning: quality validation failed for PR #{pr_number}: {e}"),
+    }
+}
+
+/// Push events carry no bounty-specific action today; logged so the daemon's
+/// event coverage is visible without requiring `issues`/`pull_request` traffic.
+fn dispatch_push_event(state: &ServeState, payload: &serde_json::Value) {
+    let repo_full = payload["repository"]["full_name"].as_str().unwrap_or("");
+    if !state.config.watched_repos.is_empty()
+        && !state.config.watched_repos.iter().any(|r| r == repo_full)
+    {
+        return;
+    }
+
+    let reference = payload["ref"].as_str().unwrap_or("");
+    let pusher = payload["pusher"]["name"].as_str().unwrap_or("unknown");
+    println!("📬 Webhook event: push to {reference} in {repo_full} by {pusher}");
+}
@@ -0,0 +1,212 @@
+//! Workload Files - Declarative, reproducible multi-repo runs
+//!
+//! This module handles:
+//! - Parsing a JSON workload describing a named batch run
+//! - Driving the existing scan/analyze/claim/submit pipeline from it
+//! - Emitting a structured JSON result report
+
+use crate::analyzer::analyze_bounty;
+use crate::audit::AuditStore;
+use crate::corpus::BountyCorpus;
+use crate::http;
+use crate::scanner::{scan_multiple_repos, BountyLead};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Which phases of the pipeline a workload should execute for each repo.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct WorkloadPhases {
+    #[serde(default)]
+    pub scan: bool,
+    #[serde(default)]
+    pub analyze: bool,
+    #[serde(default)]
+    pub claim: bool,
+    #[serde(default)]
+    pub submit: bool,
+}
+
+/// Per-repo filters limiting which bounties a workload acts on.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct RepoFilter {
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub min_reward: Option<u64>,
+    #[serde(default)]
+    pub max_complexity: Option<String>,
+}
+
+/// A single repository target within a workload.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkloadRepo {
+    pub owner: String,
+    pub repo: String,
+    #[serde(default)]
+    pub filter: RepoFilter,
+}
+
+/// The full declarative description of a batched run.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Workload {
+    pub name: String,
+    pub repos: Vec<WorkloadRepo>,
+    pub phases: WorkloadPhases,
+    pub wallet: String,
+    pub handle: String,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl Workload {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let data = std::fs::read_to_string(path.as_ref())
+            .with_context(|| format!("Failed to read workload file {}", path.as_ref().display()))?;
+        serde_json::from_str(&data).context("Failed to parse workload JSON")
+    }
+}
+
+/// The outcome of running a workload against a single bounty.
+#[derive(Debug, Clone, Serialize)]
+pub struct BountyOutcome {
+    pub repository: String,
+    pub issue_number: u64,
+    pub title: String,
+    pub complexity: String,
+    pub matched_filter: bool,
+    /// Audits a contributor would need to perform before this bounty could
+    /// close, from `AuditStore::missing_audits`. Empty unless `analyze` ran.
+    pub required_audits: Vec<String>,
+}
+
+/// The full structured result of a workload run, suitable for diffing across runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkloadReport {
+    pub workload_name: String,
+    pub repos_scanned: usize,
+    pub bounties_found: usize,
+    pub bounties_matched: usize,
+    pub duration_ms: u128,
+    pub outcomes: Vec<BountyOutcome>,
+}
+
+fn matches_filter(bounty: &BountyLead, filter: &RepoFilter) -> bool {
+    if !filter.labels.is_empty()
+        && !filter
+            .labels
+            .iter()
+            .any(|want| bounty.labels.iter().any(|have| have.eq_ignore_ascii_case(want)))
+    {
+        return false;
+    }
+
+    if let Some(min_reward) = filter.min_reward {
+        let parsed: u64 = bounty
+            .reward_estimate
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect::<String>()
+            .parse()
+            .unwrap_or(0);
+        if parsed < min_reward {
+            return false;
+        }
+    }
+
+    if let Some(max_complexity) = &filter.max_complexity {
+        let rank = |s: &str| match s.to_lowercase().as_str() {
+            "normal" => 0,
+            "medium" => 1,
+            "high" => 2,
+            "critical" => 3,
+            _ => 0,
+        };
+        if rank(&bounty.difficulty) > rank(max_complexity) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Runs the workload end-to-end: scans every configured repo, applies per-repo
+/// filters, analyzes matching bounties, and returns a structured report.
+///
+/// `phases.claim` and `phases.submit` aren't implemented yet -- claiming and
+/// submitting need live credentials, a git checkout, and should honor
+/// `dry_run` explicitly, none of which this report-only pass does. A workload
+/// that asks for them is rejected up front rather than silently treated as a
+/// scan/analyze-only run.
+pub async fn run_workload(
+    workload: &Workload,
+    github_token: &str,
+    corpus: Option<&BountyCorpus>,
+    audits: Option<&AuditStore>,
+) -> Result<WorkloadReport> {
+    if workload.phases.claim || workload.phases.submit {
+        bail!(
+            "workload '{}' requests claim/submit phases, which run_workload does not yet \
+             implement; drive claiming and submission through the `claim`/`submit` \
+             subcommands instead",
+            workload.name
+        );
+    }
+
+    let started = std::time::Instant::now();
+    let repos: Vec<(&str, &str)> = workload
+        .repos
+        .iter()
+        .map(|target| (target.owner.as_str(), target.repo.as_str()))
+        .collect();
+
+    let bounties = if workload.phases.scan {
+        scan_multiple_repos(repos, github_token, http::default_transport()).await?
+    } else {
+        Vec::new()
+    };
+
+    let mut outcomes = Vec::new();
+    let mut matched = 0usize;
+
+    for bounty in &bounties {
+        let filter = workload
+            .repos
+            .iter()
+            .find(|r| format!("{}/{}", r.owner, r.repo) == bounty.repository)
+            .map(|r| &r.filter);
+
+        let matched_filter = filter.map(|f| matches_filter(bounty, f)).unwrap_or(true);
+        if matched_filter {
+            matched += 1;
+        }
+
+        let (complexity, required_audits) = if workload.phases.analyze && matched_filter {
+            let analysis = analyze_bounty(bounty.number, &bounty.title, &bounty.body, corpus, audits);
+            (
+                analysis.technical_complexity.as_str().to_string(),
+                analysis.required_audits,
+            )
+        } else {
+            (String::new(), Vec::new())
+        };
+
+        outcomes.push(BountyOutcome {
+            repository: bounty.repository.clone(),
+            issue_number: bounty.number,
+            title: bounty.title.clone(),
+            complexity,
+            matched_filter,
+            required_audits,
+        });
+    }
+
+    Ok(WorkloadReport {
+        workload_name: workload.name.clone(),
+        repos_scanned: workload.repos.len(),
+        bounties_found: bounties.len(),
+        bounties_matched: matched,
+        duration_ms: started.elapsed().as_millis(),
+        outcomes,
+    })
+}